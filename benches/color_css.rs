@@ -0,0 +1,52 @@
+//! Turning a color into CSS with naive `format!`, `to_css_rgba` and `write_css` into a
+//! reused buffer
+//!
+//! Run with `cargo bench --bench color_css`
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use dioxus_motion::prelude::Color;
+
+/// A color whose alpha changes from call to call, as it would while animating
+fn color(i: usize) -> Color {
+    Color::new(0.2, 0.4, 0.6, (i & 255) as f32 / 255.0)
+}
+
+fn color_to_css(c: &mut Criterion) {
+    let mut group = c.benchmark_group("color to css");
+
+    group.bench_function("format!", |b| {
+        let mut i = 0;
+        b.iter(|| {
+            i += 1;
+            let color = color(black_box(i));
+            let (r, g, b, _) = color.to_rgba();
+            format!("rgba({r}, {g}, {b}, {})", color.a)
+        });
+    });
+
+    group.bench_function("to_css_rgba", |b| {
+        let mut i = 0;
+        b.iter(|| {
+            i += 1;
+            color(black_box(i)).to_css_rgba()
+        });
+    });
+
+    group.bench_function("write_css", |b| {
+        let mut i = 0;
+        let mut css = String::new();
+        b.iter(|| {
+            i += 1;
+            css.clear();
+            color(black_box(i)).write_css(&mut css);
+            black_box(&css);
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, color_to_css);
+criterion_main!(benches);
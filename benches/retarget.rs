@@ -0,0 +1,39 @@
+//! Moving the target of a running spring with `retarget` against restarting it with
+//! `animate_to`
+//!
+//! Run with `cargo bench --bench retarget`
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use dioxus_motion::Motion;
+use dioxus_motion::prelude::{AnimationConfig, AnimationMode, Spring};
+
+fn retarget_vs_animate_to(c: &mut Criterion) {
+    let config = AnimationConfig::new(AnimationMode::Spring(Spring::default()));
+    let mut group = c.benchmark_group("move target");
+
+    group.bench_function("retarget", |b| {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(1.0, config.clone());
+        let mut target = 0.0f32;
+        b.iter(|| {
+            target += 1.0;
+            motion.retarget(black_box(target));
+        });
+    });
+
+    group.bench_function("animate_to", |b| {
+        let mut motion = Motion::new(0.0f32);
+        let mut target = 0.0f32;
+        b.iter(|| {
+            target += 1.0;
+            motion.animate_to(black_box(target), config.clone());
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, retarget_vs_animate_to);
+criterion_main!(benches);
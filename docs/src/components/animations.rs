@@ -41,7 +41,7 @@ fn BasicValueAnimation() -> Element {
                 1.0,
                 AnimationConfig::new(AnimationMode::Tween(Tween {
                     duration: std::time::Duration::from_millis(500),
-                    easing: easer::functions::Cubic::ease_in_out,
+                    easing: easer::functions::Cubic::ease_in_out.into(),
                 })),
             );
         } else {
@@ -49,7 +49,7 @@ fn BasicValueAnimation() -> Element {
                 0.0,
                 AnimationConfig::new(AnimationMode::Tween(Tween {
                     duration: std::time::Duration::from_millis(500),
-                    easing: easer::functions::Cubic::ease_in_out,
+                    easing: easer::functions::Cubic::ease_in_out.into(),
                 })),
             );
         }
@@ -99,15 +99,8 @@ fn TransformAnimation() -> Element {
         }
     });
 
-    let transform_style = use_memo(move || {
-        format!(
-            "transform: translate({}px, {}px) scale({}) rotate({}deg);",
-            transform.get_value().x,
-            transform.get_value().y,
-            transform.get_value().scale,
-            transform.get_value().rotation * 180.0 / std::f32::consts::PI
-        )
-    });
+    let transform_style =
+        use_memo(move || format!("transform: {};", transform.get_value().to_css_transform()));
 
     rsx! {
         div { class: "space-y-4 w-full text-center",
@@ -179,6 +172,14 @@ impl Animatable for ColorValue {
             b: self.b + (target.b - self.b) * t,
         }
     }
+
+    fn map_within(&self, min: &Self, max: &Self, f: &dyn Fn(f32, f32, f32) -> f32) -> Self {
+        ColorValue {
+            r: f(self.r, min.r, max.r),
+            g: f(self.g, min.g, max.g),
+            b: f(self.b, min.b, max.b),
+        }
+    }
 }
 
 #[component]
@@ -347,7 +348,7 @@ use_effect(move || {
         1.0,
         AnimationConfig::new(AnimationMode::Tween(Tween {
             duration: Duration::from_millis(500),
-            easing: easer::functions::Cubic::ease_in_out,
+            easing: easer::functions::Cubic::ease_in_out.into(),
         })),
     );
 });
@@ -463,7 +464,7 @@ transform.animate_to(
     Transform::new(0.0, 0.0, 1.0, 0.0),
     AnimationConfig::new(AnimationMode::Tween(Tween {
         duration: Duration::from_millis(300),
-        easing: easer::functions::Cubic::ease_out,
+        easing: easer::functions::Cubic::ease_out.into(),
     })),
 );"#.to_string(),
                 TransformAnimation {}
@@ -623,7 +624,7 @@ fn AdvancedFeaturesAnimation() -> Element {
             1.0,
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_millis(1000),
-                easing: easer::functions::Cubic::ease_in_out,
+                easing: easer::functions::Cubic::ease_in_out.into(),
             }))
             .with_loop(LoopMode::Infinite),
         );
@@ -644,7 +645,7 @@ fn AdvancedFeaturesAnimation() -> Element {
             1.0,
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_millis(1000),
-                easing: easer::functions::Cubic::ease_in_out,
+                easing: easer::functions::Cubic::ease_in_out.into(),
             }))
             .with_loop(LoopMode::Times(3))
             .with_on_complete(|| println!("Animation completed after 3 loops!")),
@@ -657,21 +658,21 @@ fn AdvancedFeaturesAnimation() -> Element {
             0.0,
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_millis(500),
-                easing: easer::functions::Cubic::ease_out,
+                easing: easer::functions::Cubic::ease_out.into(),
             })),
         );
         delayed_value.animate_to(
             0.0,
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_millis(500),
-                easing: easer::functions::Cubic::ease_out,
+                easing: easer::functions::Cubic::ease_out.into(),
             })),
         );
         callback_value.animate_to(
             0.0,
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_millis(500),
-                easing: easer::functions::Cubic::ease_out,
+                easing: easer::functions::Cubic::ease_out.into(),
             })),
         );
     };
@@ -46,7 +46,7 @@ pub fn NavBar() -> Element {
             1.0,
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_millis(300),
-                easing: easer::functions::Cubic::ease_out,
+                easing: easer::functions::Cubic::ease_out.into(),
             })),
         );
     });
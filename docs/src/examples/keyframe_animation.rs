@@ -10,22 +10,22 @@ fn KeyframeExample() -> Element {
             .add_keyframe(
                 Transform::new(0.0, 0.0, 1.0, 0.0),
                 0.0,
-                Some(Box::new(easer::functions::Cubic::ease_in)),
+                Some(easer::functions::Cubic::ease_in.into()),
             )
             .add_keyframe(
                 Transform::new(100.0, 0.0, 1.5, 45.0),
                 0.3,
-                Some(Box::new(easer::functions::Elastic::ease_out)),
+                Some(easer::functions::Elastic::ease_out.into()),
             )
             .add_keyframe(
                 Transform::new(100.0, 100.0, 0.8, 180.0),
                 0.7,
-                Some(Box::new(easer::functions::Bounce::ease_out)),
+                Some(easer::functions::Bounce::ease_out.into()),
             )
             .add_keyframe(
                 Transform::new(0.0, 0.0, 1.0, 360.0),
                 1.0,
-                Some(Box::new(easer::functions::Back::ease_in_out)),
+                Some(easer::functions::Back::ease_in_out.into()),
             )
             .with_loop_mode(LoopMode::Alternate);
 
@@ -73,6 +73,15 @@ impl Animatable for PetalTransform {
             self.translate_y + (target.translate_y - self.translate_y) * t,
         )
     }
+
+    fn map_within(&self, min: &Self, max: &Self, f: &dyn Fn(f32, f32, f32) -> f32) -> Self {
+        Self::new(
+            f(self.rotate, min.rotate, max.rotate),
+            f(self.scale, min.scale, max.scale),
+            f(self.translate_x, min.translate_x, max.translate_x),
+            f(self.translate_y, min.translate_y, max.translate_y),
+        )
+    }
 }
 
 #[component]
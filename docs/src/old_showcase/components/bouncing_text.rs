@@ -14,10 +14,11 @@ fn BouncingLetter(letter: char, delay: f32) -> Element {
                 scale: 1.5,
                 rotation: 5.0 * (std::f32::consts::PI / 180.0),
                 x: 0.0,
+                ..Default::default()
             },
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_secs(1),
-                easing: easer::functions::Sine::ease_in_out,
+                easing: easer::functions::Sine::ease_in_out.into(),
             }))
             .with_loop(LoopMode::Infinite)
             .with_delay(delay),
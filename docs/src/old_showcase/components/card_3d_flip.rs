@@ -24,6 +24,7 @@ pub fn Card3DFlip() -> Element {
                     scale: 1.0,
                     x: 0.0,
                     y: 0.0,
+                    ..Default::default()
                 },
                 AnimationConfig::new(AnimationMode::Spring(Spring {
                     stiffness: 200.0, // Increased for snappier response
@@ -94,6 +94,17 @@ impl Animatable for Transform3D {
             self.scale + (target.scale - self.scale) * t,
         )
     }
+
+    fn map_within(&self, min: &Self, max: &Self, f: &dyn Fn(f32, f32, f32) -> f32) -> Self {
+        Self::new(
+            f(self.rotate_x, min.rotate_x, max.rotate_x),
+            f(self.rotate_y, min.rotate_y, max.rotate_y),
+            f(self.rotate_z, min.rotate_z, max.rotate_z),
+            f(self.translate_x, min.translate_x, max.translate_x),
+            f(self.translate_y, min.translate_y, max.translate_y),
+            f(self.scale, min.scale, max.scale),
+        )
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -6,38 +6,36 @@ const PERSPECTIVE: f32 = 800.0; // Increased perspective for more dramatic 3D ef
 
 #[component]
 pub fn InteractiveCube() -> Element {
-    let mut rotation_x = use_motion(0.0f32);
-    let mut rotation_y = use_motion(0.0f32);
-    let mut rotation_z = use_motion(0.0f32); // Added Z rotation for more dynamics
-    let mut scale = use_motion(1.0f32);
+    // Lift, scale and all three rotations share one motion so they can't drift apart
+    let mut cube = use_motion(Transform::identity());
     let mut glow = use_motion(0.2f32); // Initial subtle glow
-    let mut hover_lift = use_motion(0.0f32); // New hover effect
 
     let onclick = move |_e: Event<MouseData>| {
-        // Enhanced spin animation
-        let spin_sequence = AnimationSequence::new().then(
-            rotation_y.get_value() + 360.0,
-            AnimationConfig::new(AnimationMode::Spring(Spring {
-                stiffness: 150.0,
-                damping: 12.0,
-                mass: 1.0,
-                velocity: 25.0,
-            })),
-        );
+        let current = cube.get_value();
 
-        // Enhanced bounce animation
-        let bounce_sequence = AnimationSequence::new()
+        // Spin a full turn while bouncing bigger and wobbling on the Z axis, then settle back
+        let spin_sequence = AnimationSequence::new()
             .then(
-                1.3, // Bigger bounce
+                Transform {
+                    scale: 1.3, // Bigger bounce
+                    rotate_y: current.rotate_y + 360.0,
+                    rotate_z: 15.0,
+                    ..current
+                },
                 AnimationConfig::new(AnimationMode::Spring(Spring {
-                    stiffness: 400.0,
-                    damping: 8.0,
+                    stiffness: 150.0,
+                    damping: 12.0,
                     mass: 1.0,
-                    velocity: 8.0,
+                    velocity: 25.0,
                 })),
             )
             .then(
-                1.0,
+                Transform {
+                    scale: 1.0,
+                    rotate_y: current.rotate_y + 360.0,
+                    rotate_z: 0.0,
+                    ..current
+                },
                 AnimationConfig::new(AnimationMode::Spring(Spring {
                     stiffness: 300.0,
                     damping: 15.0,
@@ -46,30 +44,7 @@ pub fn InteractiveCube() -> Element {
                 })),
             );
 
-        // Z-axis wobble effect
-        let wobble_sequence = AnimationSequence::new()
-            .then(
-                15.0,
-                AnimationConfig::new(AnimationMode::Spring(Spring {
-                    stiffness: 200.0,
-                    damping: 5.0,
-                    mass: 0.5,
-                    velocity: 10.0,
-                })),
-            )
-            .then(
-                0.0,
-                AnimationConfig::new(AnimationMode::Spring(Spring {
-                    stiffness: 200.0,
-                    damping: 10.0,
-                    mass: 0.5,
-                    velocity: 0.0,
-                })),
-            );
-
-        scale.animate_sequence(bounce_sequence);
-        rotation_y.animate_sequence(spin_sequence);
-        rotation_z.animate_sequence(wobble_sequence);
+        cube.animate_sequence(spin_sequence);
 
         // Enhanced glow effect
         glow.animate_to(
@@ -90,36 +65,37 @@ pub fn InteractiveCube() -> Element {
         );
     };
 
+    let tilt_config = || {
+        AnimationConfig::new(AnimationMode::Spring(Spring {
+            stiffness: 150.0,
+            damping: 15.0,
+            mass: 0.8,
+            velocity: 0.0,
+        }))
+    };
+
     let onmousemove = move |e: Event<MouseData>| {
         let rect = e.data().client_coordinates();
         let x = (rect.x as f32 - CONTAINER_SIZE / 2.0) / (CONTAINER_SIZE / 2.0);
         let y = (rect.y as f32 - CONTAINER_SIZE / 2.0) / (CONTAINER_SIZE / 2.0);
 
         // Smoother rotation response
-        rotation_x.animate_to(
-            -y * 30.0, // Inverted for natural movement
-            AnimationConfig::new(AnimationMode::Spring(Spring {
-                stiffness: 150.0,
-                damping: 15.0,
-                mass: 0.8,
-                velocity: 0.0,
-            })),
-        );
-
-        rotation_y.animate_to(
-            x * 30.0,
-            AnimationConfig::new(AnimationMode::Spring(Spring {
-                stiffness: 150.0,
-                damping: 15.0,
-                mass: 0.8,
-                velocity: 0.0,
-            })),
+        cube.animate_to(
+            Transform {
+                rotate_x: -y * 30.0, // Inverted for natural movement
+                rotate_y: x * 30.0,
+                ..cube.get_value()
+            },
+            tilt_config(),
         );
     };
 
     let onmouseenter = move |_| {
-        hover_lift.animate_to(
-            20.0,
+        cube.animate_to(
+            Transform {
+                y: -20.0, // Lift the cube on hover
+                ..cube.get_value()
+            },
             AnimationConfig::new(AnimationMode::Spring(Spring {
                 stiffness: 200.0,
                 damping: 15.0,
@@ -130,35 +106,15 @@ pub fn InteractiveCube() -> Element {
     };
 
     let onmouseleave = move |_| {
-        hover_lift.animate_to(
-            0.0,
-            AnimationConfig::new(AnimationMode::Spring(Spring {
-                stiffness: 200.0,
-                damping: 15.0,
-                mass: 0.8,
-                velocity: 0.0,
-            })),
-        );
-
-        // Reset rotations
-        rotation_x.animate_to(
-            0.0,
-            AnimationConfig::new(AnimationMode::Spring(Spring {
-                stiffness: 150.0,
-                damping: 15.0,
-                mass: 0.8,
-                velocity: 0.0,
-            })),
-        );
-
-        rotation_y.animate_to(
-            0.0,
-            AnimationConfig::new(AnimationMode::Spring(Spring {
-                stiffness: 150.0,
-                damping: 15.0,
-                mass: 0.8,
-                velocity: 0.0,
-            })),
+        // Drop back down and reset rotations
+        cube.animate_to(
+            Transform {
+                y: 0.0,
+                rotate_x: 0.0,
+                rotate_y: 0.0,
+                ..cube.get_value()
+            },
+            tilt_config(),
         );
     };
 
@@ -175,7 +131,7 @@ pub fn InteractiveCube() -> Element {
             // Shadow
             div {
                 class: "absolute bottom-0 left-1/2 -translate-x-1/2 bg-black/20 blur-xl rounded-full transition-all duration-300",
-                style: "width: {CONTAINER_SIZE * 0.8}px; height: {CONTAINER_SIZE * 0.1}px; transform: translateY({20.0 + hover_lift.get_value()}px) scale({scale.get_value()}, 1.0)",
+                style: "width: {CONTAINER_SIZE * 0.8}px; height: {CONTAINER_SIZE * 0.1}px; transform: translateY({20.0 - cube.get_value().y}px) scale({cube.get_value().scale}, 1.0)",
             }
 
             div {
@@ -184,7 +140,7 @@ pub fn InteractiveCube() -> Element {
                 onmouseenter,
                 onmouseleave,
                 class: "relative w-full h-full items-center justify-center transform-style-3d transition-all duration-100",
-                style: "transform: translateY(-{hover_lift.get_value()}px) rotateX({rotation_x.get_value()}deg) rotateY({rotation_y.get_value()}deg) rotateZ({rotation_z.get_value()}deg) scale({scale.get_value()})",
+                style: "transform: {cube.get_value().to_css_transform()}",
                 // Front face with enhanced gradient
                 div {
                     class: "absolute w-full h-full flex items-center justify-center text-2xl font-bold text-white bg-linear-to-br from-blue-500 to-blue-600 shadow-lg transform translate-z-[100px] opacity-90 hover:opacity-100 transition-all duration-300",
@@ -76,6 +76,7 @@ pub fn MorphingShape(shapes: Vec<&'static str>, duration: f32) -> Element {
                 scale: 1.2,
                 x: 0.0,
                 y: 0.0,
+                ..Default::default()
             },
             AnimationConfig::new(AnimationMode::Spring(Spring {
                 stiffness: 35.0, // Reduced for more fluid motion
@@ -12,7 +12,7 @@ pub fn PathAnimation(path: &'static str, duration: f32) -> Element {
             0.0,
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_secs_f32(duration),
-                easing: easer::functions::Cubic::ease_in_out,
+                easing: easer::functions::Cubic::ease_in_out.into(),
             }))
             .with_loop(LoopMode::Infinite),
         );
@@ -11,7 +11,7 @@ pub fn ProgressBar(title: &'static str) -> Element {
             100.0,
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_secs(5),
-                easing: easer::functions::Sine::ease_in_out,
+                easing: easer::functions::Sine::ease_in_out.into(),
             }))
             .with_loop(LoopMode::Infinite),
         );
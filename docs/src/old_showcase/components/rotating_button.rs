@@ -45,7 +45,7 @@ pub fn RotatingButton() -> Element {
             360.0,
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_millis(800),     // Faster rotation
-                easing: easer::functions::Expo::ease_out, // Smoother deceleration
+                easing: easer::functions::Expo::ease_out.into(), // Smoother deceleration
             })),
         );
 
@@ -59,7 +59,7 @@ fn StepOne() -> Element {
             100.0,
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_millis(1000),
-                easing: easer::functions::Linear::ease_in_out,
+                easing: easer::functions::Linear::ease_in_out.into(),
             })),
         );
     };
@@ -99,7 +99,7 @@ value.animate_to(
     100.0,
     AnimationConfig::new(AnimationMode::Tween(Tween {
         duration: Duration::from_millis(1000),
-        easing: easer::functions::Linear::ease_in_out,
+        easing: easer::functions::Linear::ease_in_out.into(),
     })),
 );"#.to_string(),
                             language: "rust".to_string(),
@@ -179,7 +179,7 @@ fn StepTwo() -> Element {
             100.0,
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_millis(1000),
-                easing: easer::functions::Cubic::ease_in_out,
+                easing: easer::functions::Cubic::ease_in_out.into(),
             })),
         );
     };
@@ -252,7 +252,7 @@ fn StepTwo() -> Element {
                     // Code snippet
                     div { class: "bg-dark-200/50 p-2 rounded-lg text-xs mb-3",
                         code { class: "text-primary/90",
-                            "AnimationMode::Tween(Tween {{\n  duration: Duration::from_millis(1000),\n  easing: easer::functions::Cubic::ease_in_out\n}})"
+                            "AnimationMode::Tween(Tween {{\n  duration: Duration::from_millis(1000),\n  easing: easer::functions::Cubic::ease_in_out.into()\n}})"
                         }
                     }
 
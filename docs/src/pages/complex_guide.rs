@@ -111,6 +111,15 @@ impl dioxus_motion::animations::utils::Animatable for PetalTransform {
             self.translate_y + (other.translate_y - self.translate_y) * t,
         )
     }
+
+    fn map_within(&self, min: &Self, max: &Self, f: &dyn Fn(f32, f32, f32) -> f32) -> Self {
+        Self::new(
+            f(self.rotate, min.rotate, max.rotate),
+            f(self.scale, min.scale, max.scale),
+            f(self.translate_x, min.translate_x, max.translate_x),
+            f(self.translate_y, min.translate_y, max.translate_y),
+        )
+    }
 }
 
 #[component]
@@ -255,6 +264,15 @@ impl dioxus_motion::animations::utils::Animatable for PetalTransform {
             self.translate_y + (other.translate_y - self.translate_y) * t,
         )
     }
+
+    fn map_within(&self, min: &Self, max: &Self, f: &dyn Fn(f32, f32, f32) -> f32) -> Self {
+        Self::new(
+            f(self.rotate, min.rotate, max.rotate),
+            f(self.scale, min.scale, max.scale),
+            f(self.translate_x, min.translate_x, max.translate_x),
+            f(self.translate_y, min.translate_y, max.translate_y),
+        )
+    }
 }"#.to_string(),
                         language: "rust".to_string(),
                     }
@@ -412,22 +430,22 @@ fn StepThree() -> Element {
             .add_keyframe(
                 PetalTransform::zero(),
                 0.0,
-                Some(easer::functions::Cubic::ease_in),
+                Some(easer::functions::Cubic::ease_in.into()),
             )
             .add_keyframe(
                 PetalTransform::new(45.0, 1.2, 10.0, -10.0),
                 0.3,
-                Some(easer::functions::Elastic::ease_out),
+                Some(easer::functions::Elastic::ease_out.into()),
             )
             .add_keyframe(
                 PetalTransform::new(-45.0, 1.5, -10.0, 10.0),
                 0.7,
-                Some(easer::functions::Bounce::ease_out),
+                Some(easer::functions::Bounce::ease_out.into()),
             )
             .add_keyframe(
                 PetalTransform::zero(),
                 1.0,
-                Some(easer::functions::Back::ease_in_out),
+                Some(easer::functions::Back::ease_in_out.into()),
             );
 
         petal.animate_keyframes(keyframes);
@@ -474,22 +492,22 @@ petal.animate_sequence(sequence);"#.to_string(),
     .add_keyframe(
         PetalTransform::zero(),
         0.0,
-        Some(easer::functions::Cubic::ease_in),
+        Some(easer::functions::Cubic::ease_in.into()),
     )
     .add_keyframe(
         PetalTransform::new(45.0, 1.2, 10.0, -10.0),
         0.3,
-        Some(easer::functions::Elastic::ease_out),
+        Some(easer::functions::Elastic::ease_out.into()),
     )
     .add_keyframe(
         PetalTransform::new(-45.0, 1.5, -10.0, 10.0),
         0.7,
-        Some(easer::functions::Bounce::ease_out),
+        Some(easer::functions::Bounce::ease_out.into()),
     )
     .add_keyframe(
         PetalTransform::zero(),
         1.0,
-        Some(easer::functions::Back::ease_in_out),
+        Some(easer::functions::Back::ease_in_out.into()),
     );
 
 petal.animate_keyframes(keyframes);"#.to_string(),
@@ -299,22 +299,22 @@ fn StepThree() -> Element {
             .add_keyframe(
                 PetalTransform::zero(),
                 0.0,
-                Some(easer::functions::Cubic::ease_in),
+                Some(easer::functions::Cubic::ease_in.into()),
             )
             .add_keyframe(
                 PetalTransform::new(45.0, 1.2, 10.0, -10.0),
                 0.3,
-                Some(easer::functions::Elastic::ease_out),
+                Some(easer::functions::Elastic::ease_out.into()),
             )
             .add_keyframe(
                 PetalTransform::new(-45.0, 1.5, -10.0, 10.0),
                 0.7,
-                Some(easer::functions::Bounce::ease_out),
+                Some(easer::functions::Bounce::ease_out.into()),
             )
             .add_keyframe(
                 PetalTransform::zero(),
                 1.0,
-                Some(easer::functions::Back::ease_in_out),
+                Some(easer::functions::Back::ease_in_out.into()),
             );
 
         petal.animate_keyframes(keyframes);
@@ -361,22 +361,22 @@ petal.animate_sequence(sequence);"#.to_string(),
     .add_keyframe(
         PetalTransform::zero(),
         0.0,
-        Some(easer::functions::Cubic::ease_in),
+        Some(easer::functions::Cubic::ease_in.into()),
     )
     .add_keyframe(
         PetalTransform::new(45.0, 1.2, 10.0, -10.0),
         0.3,
-        Some(easer::functions::Elastic::ease_out),
+        Some(easer::functions::Elastic::ease_out.into()),
     )
     .add_keyframe(
         PetalTransform::new(-45.0, 1.5, -10.0, 10.0),
         0.7,
-        Some(easer::functions::Bounce::ease_out),
+        Some(easer::functions::Bounce::ease_out.into()),
     )
     .add_keyframe(
         PetalTransform::zero(),
         1.0,
-        Some(easer::functions::Back::ease_in_out),
+        Some(easer::functions::Back::ease_in_out.into()),
     );
 
 petal.animate_keyframes(keyframes);"#.to_string(),
@@ -75,7 +75,7 @@ fn StepOne() -> Element {
             100.0,
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_millis(1000),
-                easing: easer::functions::Cubic::ease_in_out,
+                easing: easer::functions::Cubic::ease_in_out.into(),
             }))
             .with_loop(LoopMode::Infinite),
         );
@@ -86,7 +86,7 @@ fn StepOne() -> Element {
             100.0,
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_millis(1000),
-                easing: easer::functions::Cubic::ease_in_out,
+                easing: easer::functions::Cubic::ease_in_out.into(),
             }))
             .with_loop(LoopMode::Times(3)),
         );
@@ -97,7 +97,7 @@ fn StepOne() -> Element {
             100.0,
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_millis(1000),
-                easing: easer::functions::Cubic::ease_in_out,
+                easing: easer::functions::Cubic::ease_in_out.into(),
             }))
             .with_loop(LoopMode::Alternate),
         );
@@ -108,7 +108,7 @@ fn StepOne() -> Element {
             100.0,
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_millis(1000),
-                easing: easer::functions::Cubic::ease_in_out,
+                easing: easer::functions::Cubic::ease_in_out.into(),
             }))
             .with_loop(LoopMode::AlternateTimes(3)),
         );
@@ -371,10 +371,10 @@ fn StepThree() -> Element {
 
     let start_keyframes = move |_| {
         let keyframes = KeyframeAnimation::new(Duration::from_secs(2))
-            .add_keyframe(0.0, 0.0, Some(easer::functions::Cubic::ease_in))
-            .add_keyframe(100.0, 0.3, Some(easer::functions::Elastic::ease_out))
-            .add_keyframe(50.0, 0.7, Some(easer::functions::Bounce::ease_out))
-            .add_keyframe(0.0, 1.0, Some(easer::functions::Back::ease_in_out));
+            .add_keyframe(0.0, 0.0, Some(easer::functions::Cubic::ease_in.into()))
+            .add_keyframe(100.0, 0.3, Some(easer::functions::Elastic::ease_out.into()))
+            .add_keyframe(50.0, 0.7, Some(easer::functions::Bounce::ease_out.into()))
+            .add_keyframe(0.0, 1.0, Some(easer::functions::Back::ease_in_out.into()));
 
         keyframe_value.animate_keyframes(keyframes);
     };
@@ -411,10 +411,10 @@ value.animate_sequence(sequence);
 
 // Keyframe animation
 let keyframes = KeyframeAnimation::new(Duration::from_secs(2))
-    .add_keyframe(0.0, 0.0, Some(easer::functions::Cubic::ease_in))
-    .add_keyframe(100.0, 0.3, Some(easer::functions::Elastic::ease_out))
-    .add_keyframe(50.0, 0.7, Some(easer::functions::Bounce::ease_out))
-    .add_keyframe(0.0, 1.0, Some(easer::functions::Back::ease_in_out));
+    .add_keyframe(0.0, 0.0, Some(easer::functions::Cubic::ease_in.into()))
+    .add_keyframe(100.0, 0.3, Some(easer::functions::Elastic::ease_out.into()))
+    .add_keyframe(50.0, 0.7, Some(easer::functions::Bounce::ease_out.into()))
+    .add_keyframe(0.0, 1.0, Some(easer::functions::Back::ease_in_out.into()));
 value.animate_keyframes(keyframes);"#.to_string(),
                         language: "rust".to_string(),
                     }
@@ -538,34 +538,34 @@ fn StepFour() -> Element {
             .add_keyframe(
                 Transform::identity(),
                 0.0,
-                Some(easer::functions::Cubic::ease_in),
+                Some(easer::functions::Cubic::ease_in.into()),
             )
             .add_keyframe(
                 Transform::new(100.0, 50.0, 1.2, 180.0),
                 0.5,
-                Some(easer::functions::Elastic::ease_out),
+                Some(easer::functions::Elastic::ease_out.into()),
             )
             .add_keyframe(
                 Transform::identity(),
                 1.0,
-                Some(easer::functions::Back::ease_in_out),
+                Some(easer::functions::Back::ease_in_out.into()),
             );
 
         let color_keyframes = KeyframeAnimation::new(Duration::from_secs(2))
             .add_keyframe(
                 Color::from_rgba(59, 130, 246, 255),
                 0.0,
-                Some(easer::functions::Cubic::ease_in),
+                Some(easer::functions::Cubic::ease_in.into()),
             )
             .add_keyframe(
                 Color::from_rgba(236, 72, 153, 255),
                 0.5,
-                Some(easer::functions::Cubic::ease_out),
+                Some(easer::functions::Cubic::ease_out.into()),
             )
             .add_keyframe(
                 Color::from_rgba(59, 130, 246, 255),
                 1.0,
-                Some(easer::functions::Cubic::ease_in_out),
+                Some(easer::functions::Cubic::ease_in_out.into()),
             );
 
         keyframe_transform.animate_keyframes(transform_keyframes);
@@ -742,17 +742,17 @@ let transform_keyframes = KeyframeAnimation::new(Duration::from_secs(2))
     .add_keyframe(
         Transform::identity(),
         0.0,
-        Some(easer::functions::Cubic::ease_in),
+        Some(easer::functions::Cubic::ease_in.into()),
     )
     .add_keyframe(
         Transform::new(100.0, 50.0, 1.2, 180.0),
         0.5,
-        Some(easer::functions::Elastic::ease_out),
+        Some(easer::functions::Elastic::ease_out.into()),
     )
     .add_keyframe(
         Transform::identity(),
         1.0,
-        Some(easer::functions::Back::ease_in_out),
+        Some(easer::functions::Back::ease_in_out.into()),
     );
 
 // Color keyframes
@@ -760,17 +760,17 @@ let color_keyframes = KeyframeAnimation::new(Duration::from_secs(2))
     .add_keyframe(
         Color::from_rgba(59, 130, 246, 255),
         0.0,
-        Some(easer::functions::Cubic::ease_in),
+        Some(easer::functions::Cubic::ease_in.into()),
     )
     .add_keyframe(
         Color::from_rgba(236, 72, 153, 255),
         0.5,
-        Some(easer::functions::Cubic::ease_out),
+        Some(easer::functions::Cubic::ease_out.into()),
     )
     .add_keyframe(
         Color::from_rgba(59, 130, 246, 255),
         1.0,
-        Some(easer::functions::Cubic::ease_in_out),
+        Some(easer::functions::Cubic::ease_in_out.into()),
     );"#.to_string(),
                         language: "rust".to_string(),
                     }
@@ -1,18 +1,85 @@
 use proc_macro::TokenStream;
-use quote::{format_ident, quote};
-use syn::{Attribute, Data, DataEnum, DeriveInput, Fields, Meta, parse_macro_input};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    Attribute, Data, DataEnum, DeriveInput, Fields, Ident, Lit, Meta, MetaList, parse_macro_input,
+};
 
-fn get_transition_from_attrs(attrs: &[Attribute]) -> Option<String> {
-    attrs
-        .iter()
-        .find(|attr| attr.path().is_ident("transition"))
-        .and_then(|attr| {
-            if let Ok(Meta::Path(path)) = attr.parse_args::<Meta>() {
-                path.get_ident().map(|ident| ident.to_string())
-            } else {
-                None
-            }
-        })
+/// Reads the `#[transition(...)]` attribute as a `TransitionVariant` expression.
+///
+/// Bare names such as `#[transition(Fade)]` map to the unit variant of the same name, and
+/// `#[transition(Slide(direction = Left, distance_percent = 50.0))]` builds a parameterized slide.
+/// The direction can also be given on its own, as in `#[transition(Slide(Left))]`.
+fn get_transition_from_attrs(attrs: &[Attribute]) -> syn::Result<Option<TokenStream2>> {
+    let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident("transition")) else {
+        return Ok(None);
+    };
+
+    match attr.parse_args::<Meta>()? {
+        Meta::Path(path) => parse_unit_transition(&path).map(Some),
+        Meta::List(list) if list.path.is_ident("Slide") => parse_slide(&list).map(Some),
+        meta => Err(syn::Error::new_spanned(
+            meta,
+            "expected a transition such as `Fade`, `Slide(Left)` or `Slide(direction = Left, distance_percent = 50.0)`",
+        )),
+    }
+}
+
+/// Turns a bare transition name into its `TransitionVariant`; rustc reports unknown names
+fn parse_unit_transition(path: &syn::Path) -> syn::Result<TokenStream2> {
+    let ident = path.get_ident().ok_or_else(|| {
+        syn::Error::new_spanned(path, "expected a transition name such as `Fade`")
+    })?;
+    if ident == "Slide" {
+        return Err(syn::Error::new_spanned(
+            ident,
+            "`Slide` needs a direction, e.g. `Slide(Left)`",
+        ));
+    }
+    Ok(quote! { TransitionVariant::#ident })
+}
+
+/// Directions accepted by `Slide`, matching the `Direction` enum
+const DIRECTIONS: [&str; 4] = ["Left", "Right", "Up", "Down"];
+
+/// Parses the arguments of `Slide(...)`; `distance_percent` defaults to a full 100%
+fn parse_slide(list: &MetaList) -> syn::Result<TokenStream2> {
+    let mut direction: Option<Ident> = None;
+    let mut distance_percent = 100.0f32;
+
+    list.parse_nested_meta(|meta| {
+        let bare_direction = meta
+            .path
+            .get_ident()
+            .filter(|ident| DIRECTIONS.iter().any(|name| ident == name));
+        if let Some(ident) = bare_direction {
+            direction = Some(ident.clone());
+            Ok(())
+        } else if meta.path.is_ident("direction") {
+            direction = Some(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("distance_percent") {
+            distance_percent = match meta.value()?.parse()? {
+                Lit::Float(lit) => lit.base10_parse()?,
+                Lit::Int(lit) => lit.base10_parse()?,
+                lit => return Err(syn::Error::new_spanned(lit, "expected a number")),
+            };
+            Ok(())
+        } else {
+            Err(meta
+                .error("expected `direction`, `distance_percent` or a direction such as `Left`"))
+        }
+    })?;
+
+    let direction = direction.ok_or_else(|| {
+        syn::Error::new_spanned(list, "`Slide` needs a `direction`: Left, Right, Up or Down")
+    })?;
+    Ok(quote! {
+        TransitionVariant::Slide {
+            direction: Direction::#direction,
+            distance_percent: #distance_percent,
+        }
+    })
 }
 
 // Helper to extract layout nesting information from enum variants
@@ -50,58 +117,76 @@ fn get_layout_depth(variants: &[&syn::Variant]) -> Vec<(syn::Ident, usize)> {
 #[proc_macro_derive(MotionTransitions, attributes(transition, layout, end_layout))]
 pub fn derive_route_transitions(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    expand_route_transitions(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_route_transitions(input: DeriveInput) -> syn::Result<TokenStream2> {
     let name = &input.ident;
-    let variants = match input.data {
-        Data::Enum(DataEnum { variants, .. }) => variants,
-        _ => panic!("MotionTransitions can only be derived for enums"),
+    let Data::Enum(DataEnum { variants, .. }) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            name,
+            "MotionTransitions can only be derived for enums",
+        ));
     };
 
-    let component_match_arms = variants.iter().map(|variant| {
-        let variant_ident = &variant.ident;
-        let component_name = &variant.ident;
+    let component_match_arms = variants
+        .iter()
+        .map(|variant| {
+            let variant_ident = &variant.ident;
+            let component_name = &variant.ident;
 
-        match &variant.fields {
-            Fields::Named(fields) => {
-                let field_names: Vec<_> = fields.named.iter().map(|f| &f.ident).collect();
-                quote! {
-                    Self::#variant_ident { #(#field_names,)* } => {
-                        rsx! { #component_name { #(#field_names: #field_names.clone(),)* } }
+            Ok(match &variant.fields {
+                Fields::Named(fields) => {
+                    let field_names: Vec<_> = fields.named.iter().map(|f| &f.ident).collect();
+                    quote! {
+                        Self::#variant_ident { #(#field_names,)* } => {
+                            rsx! { #component_name { #(#field_names: #field_names.clone(),)* } }
+                        }
                     }
                 }
-            }
-            Fields::Unnamed(_) => {
-                quote! { Self::#variant_ident(..) => rsx! { #component_name {} } }
-            }
-            Fields::Unit => {
-                quote! { Self::#variant_ident {} => rsx! { #component_name {} } }
-            }
-        }
-    });
-
-    let transition_match_arms = variants.iter().map(|variant| {
-        let variant_ident = &variant.ident;
-        let transition = get_transition_from_attrs(&variant.attrs)
-            .map(|t| format_ident!("{}", t))
-            .unwrap_or(format_ident!("Fade"));
-
-        match &variant.fields {
-            Fields::Named(fields) => {
-                let field_patterns = fields.named.iter().map(|f| {
-                    let name = &f.ident;
-                    quote! { #name: _ }
-                });
-                quote! {
-                    Self::#variant_ident { #(#field_patterns,)* } => TransitionVariant::#transition
+                // `Routable` only binds route parameters to named fields, so a tuple variant
+                // can't be a route and there are no props to hand its fields to
+                Fields::Unnamed(_) => {
+                    return Err(syn::Error::new_spanned(
+                        variant,
+                        "MotionTransitions needs route variants with named fields, e.g. `Post { id: usize }`",
+                    ));
                 }
-            }
-            Fields::Unnamed(_) => {
-                quote! { Self::#variant_ident(..) => TransitionVariant::#transition }
-            }
-            Fields::Unit => {
-                quote! { Self::#variant_ident {} => TransitionVariant::#transition }
-            }
-        }
-    });
+                Fields::Unit => {
+                    quote! { Self::#variant_ident {} => rsx! { #component_name {} } }
+                }
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let transition_match_arms = variants
+        .iter()
+        .map(|variant| {
+            let variant_ident = &variant.ident;
+            let transition = get_transition_from_attrs(&variant.attrs)?
+                .unwrap_or_else(|| quote! { TransitionVariant::Fade });
+
+            Ok(match &variant.fields {
+                Fields::Named(fields) => {
+                    let field_patterns = fields.named.iter().map(|f| {
+                        let name = &f.ident;
+                        quote! { #name: _ }
+                    });
+                    quote! {
+                        Self::#variant_ident { #(#field_patterns,)* } => #transition
+                    }
+                }
+                Fields::Unnamed(_) => {
+                    quote! { Self::#variant_ident(..) => #transition }
+                }
+                Fields::Unit => {
+                    quote! { Self::#variant_ident {} => #transition }
+                }
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
 
     // Generate layout depth match arms
     let layout_depths = get_layout_depth(&variants.iter().collect::<Vec<_>>());
@@ -156,5 +241,40 @@ pub fn derive_route_transitions(input: TokenStream) -> TokenStream {
         }
     };
 
-    TokenStream::from(expanded)
+    Ok(expanded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    fn expand(input: DeriveInput) -> String {
+        match expand_route_transitions(input) {
+            Ok(tokens) => tokens.to_string(),
+            Err(err) => err.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_tuple_variant_is_an_error() {
+        let expanded = expand(parse_quote! {
+            enum Route {
+                #[transition(Fade)]
+                Post(PostId, String),
+            }
+        });
+        assert_eq!(
+            expanded,
+            "MotionTransitions needs route variants with named fields, e.g. `Post { id: usize }`"
+        );
+    }
+
+    #[test]
+    fn test_non_enum_is_an_error() {
+        let expanded = expand(parse_quote! {
+            struct Route;
+        });
+        assert_eq!(expanded, "MotionTransitions can only be derived for enums");
+    }
 }
@@ -2,13 +2,53 @@
 //!
 //! Provides RGBA color representation and animation interpolation.
 //! Supports both normalized (0.0-1.0) and byte (0-255) color values.
+//! Colors can be interpolated in RGB or HSL space.
+
+use std::fmt;
 
 use crate::animations::utils::Animatable;
 
+/// Color space used when interpolating between colors
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColorSpace {
+    /// Linear interpolation of the red, green and blue channels
+    #[default]
+    Rgb,
+    /// Interpolation of hue, saturation and lightness, taking the shorter hue arc
+    Hsl,
+}
+
+/// Error returned by [`Color::from_hex`] for strings that aren't hex colors
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorParseError {
+    /// The string doesn't start with `#`
+    MissingHash,
+    /// A character after `#` isn't a hex digit
+    InvalidDigit(char),
+    /// The number of digits isn't 3, 6 or 8
+    InvalidLength(usize),
+}
+
+impl fmt::Display for ColorParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingHash => write!(f, "hex color must start with '#'"),
+            Self::InvalidDigit(c) => write!(f, "invalid hex digit {c:?} in color"),
+            Self::InvalidLength(len) => {
+                write!(f, "hex color must have 3, 6 or 8 digits, found {len}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ColorParseError {}
+
 /// Represents an RGBA color with normalized components
 ///
 /// Each component (r,g,b,a) is stored as a float between 0.0 and 1.0
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Color {
     /// Red component (0.0-1.0)
     pub r: f32,
@@ -65,6 +105,257 @@ impl Color {
             (self.a * 255.0 + 0.5) as u8,
         )
     }
+
+    /// Parses a `#RGB`, `#RRGGBB` or `#RRGGBBAA` hex string
+    ///
+    /// Colors without an alpha pair are fully opaque.
+    ///
+    /// # Examples
+    /// ```
+    /// use dioxus_motion::prelude::Color;
+    /// let orange = Color::from_hex("#ff8000").expect("valid hex color");
+    /// assert_eq!(orange, Color::from_rgba(255, 128, 0, 255));
+    /// ```
+    pub fn from_hex(hex: &str) -> Result<Self, ColorParseError> {
+        let digits = hex.strip_prefix('#').ok_or(ColorParseError::MissingHash)?;
+        let nibbles = digits
+            .chars()
+            .map(|c| {
+                c.to_digit(16)
+                    .map(|digit| digit as u8)
+                    .ok_or(ColorParseError::InvalidDigit(c))
+            })
+            .collect::<Result<Vec<u8>, _>>()?;
+
+        match nibbles[..] {
+            // Each shorthand digit is doubled, so `f` becomes `ff`
+            [r, g, b] => Ok(Self::from_rgba(r * 17, g * 17, b * 17, 255)),
+            [r1, r2, g1, g2, b1, b2] => Ok(Self::from_rgba(
+                r1 << 4 | r2,
+                g1 << 4 | g2,
+                b1 << 4 | b2,
+                255,
+            )),
+            [r1, r2, g1, g2, b1, b2, a1, a2] => Ok(Self::from_rgba(
+                r1 << 4 | r2,
+                g1 << 4 | g2,
+                b1 << 4 | b2,
+                a1 << 4 | a2,
+            )),
+            _ => Err(ColorParseError::InvalidLength(nibbles.len())),
+        }
+    }
+
+    /// Formats the color as a lowercase hex string
+    ///
+    /// Opaque colors are written as `#rrggbb`, anything else as `#rrggbbaa`.
+    pub fn to_hex(&self) -> String {
+        match self.to_rgba() {
+            (r, g, b, 255) => format!("#{r:02x}{g:02x}{b:02x}"),
+            (r, g, b, a) => format!("#{r:02x}{g:02x}{b:02x}{a:02x}"),
+        }
+    }
+
+    /// Formats the color as a CSS `rgba(r, g, b, a)` value
+    ///
+    /// Red, green and blue are written as 0-255 integers and alpha as a 0.0-1.0 decimal
+    /// with up to three places. See [`Color::write_css`] to format into a reused buffer.
+    ///
+    /// # Examples
+    /// ```
+    /// use dioxus_motion::prelude::Color;
+    /// let color = Color::from_rgba(255, 128, 0, 255);
+    /// assert_eq!(color.to_css_rgba(), "rgba(255, 128, 0, 1)");
+    /// ```
+    pub fn to_css_rgba(&self) -> String {
+        let mut css = String::with_capacity(CSS_RGBA_CAPACITY);
+        self.write_css(&mut css);
+        css
+    }
+
+    /// Formats the color as a CSS `rgb(r, g, b)` value, leaving out the alpha
+    pub fn to_css_rgb(&self) -> String {
+        let (r, g, b, _) = self.to_rgba();
+        let mut css = String::with_capacity(CSS_RGBA_CAPACITY);
+        css.push_str("rgb(");
+        push_channels(&mut css, r, g, b);
+        css.push(')');
+        css
+    }
+
+    /// Appends the color to `buf` as a CSS `rgba(r, g, b, a)` value, like
+    /// [`Color::to_css_rgba`]
+    ///
+    /// Digits are written directly rather than through `format!`, so once `buf` has grown
+    /// big enough, formatting allocates nothing. For styles built every frame, keep one
+    /// buffer around and clear it before each use.
+    ///
+    /// # Examples
+    /// ```
+    /// use dioxus_motion::prelude::Color;
+    /// let colors = [Color::from_rgba(255, 0, 0, 255), Color::new(0.0, 0.0, 1.0, 0.25)];
+    ///
+    /// let mut css = String::new();
+    /// for color in colors {
+    ///     css.clear();
+    ///     color.write_css(&mut css);
+    ///     // Use `css` while it holds this color
+    /// }
+    /// assert_eq!(css, "rgba(0, 0, 255, 0.25)");
+    /// ```
+    pub fn write_css(&self, buf: &mut String) {
+        let (r, g, b, _) = self.to_rgba();
+        buf.push_str("rgba(");
+        push_channels(buf, r, g, b);
+        buf.push_str(", ");
+        push_alpha(buf, self.a);
+        buf.push(')');
+    }
+
+    /// Interpolates towards `target` in the given color space
+    ///
+    /// # Examples
+    /// ```
+    /// use dioxus_motion::prelude::{Color, ColorSpace};
+    /// let red = Color::new(1.0, 0.0, 0.0, 1.0);
+    /// let green = Color::new(0.0, 1.0, 0.0, 1.0);
+    /// let yellow = red.interpolate_in(&green, 0.5, ColorSpace::Hsl);
+    /// ```
+    pub fn interpolate_in(&self, target: &Self, t: f32, space: ColorSpace) -> Self {
+        match space {
+            ColorSpace::Rgb => self.interpolate(target, t),
+            ColorSpace::Hsl => self.interpolate_hsl(target, t),
+        }
+    }
+
+    fn interpolate_hsl(&self, target: &Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let (h1, s1, l1) = self.to_hsl();
+        let (h2, s2, l2) = target.to_hsl();
+
+        // Grey has no meaningful hue, so borrow the other color's hue
+        let h1 = if s1 == 0.0 { h2 } else { h1 };
+        let h2 = if s2 == 0.0 { h1 } else { h2 };
+
+        // Take the shorter way around the hue circle
+        let mut hue_diff = h2 - h1;
+        if hue_diff > 180.0 {
+            hue_diff -= 360.0;
+        } else if hue_diff < -180.0 {
+            hue_diff += 360.0;
+        }
+
+        let mut hue = h1 + hue_diff * t;
+        if hue < 0.0 {
+            hue += 360.0;
+        } else if hue >= 360.0 {
+            hue -= 360.0;
+        }
+
+        Color::from_hsl(
+            hue,
+            s1 + (s2 - s1) * t,
+            l1 + (l2 - l1) * t,
+            self.a + (target.a - self.a) * t,
+        )
+    }
+
+    /// Converts to hue (degrees, 0-360), saturation and lightness (0.0-1.0)
+    fn to_hsl(self) -> (f32, f32, f32) {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+        let lightness = (max + min) / 2.0;
+        let chroma = max - min;
+
+        if chroma == 0.0 {
+            return (0.0, 0.0, lightness);
+        }
+
+        let saturation = chroma / (1.0 - (2.0 * lightness - 1.0).abs());
+        let hue = if max == self.r {
+            let hue = 60.0 * (self.g - self.b) / chroma;
+            if hue < 0.0 { hue + 360.0 } else { hue }
+        } else if max == self.g {
+            60.0 * ((self.b - self.r) / chroma + 2.0)
+        } else {
+            60.0 * ((self.r - self.g) / chroma + 4.0)
+        };
+
+        (hue, saturation, lightness)
+    }
+
+    /// Creates a color from hue (degrees, 0-360), saturation and lightness (0.0-1.0)
+    fn from_hsl(hue: f32, saturation: f32, lightness: f32, alpha: f32) -> Self {
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        let sector = hue / 60.0;
+        // Distance of the sector from the nearest even sector, i.e. |sector mod 2 - 1|
+        let x = chroma * (1.0 - (sector - 2.0 * (sector / 2.0).floor() - 1.0).abs());
+
+        let (r, g, b) = match sector as u8 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+
+        let m = lightness - chroma / 2.0;
+        Color::new(r + m, g + m, b + m, alpha)
+    }
+}
+
+/// Length of the longest `rgba(...)` value, `rgba(255, 255, 255, 0.999)`
+const CSS_RGBA_CAPACITY: usize = 27;
+
+/// Appends `r, g, b`
+fn push_channels(buf: &mut String, r: u8, g: u8, b: u8) {
+    push_byte(buf, r);
+    buf.push_str(", ");
+    push_byte(buf, g);
+    buf.push_str(", ");
+    push_byte(buf, b);
+}
+
+/// Appends a byte in decimal, without going through the formatting machinery
+fn push_byte(buf: &mut String, value: u8) {
+    let hundreds = value / 100;
+    let tens = value / 10 - hundreds * 10;
+    let ones = value - value / 10 * 10;
+    if hundreds > 0 {
+        buf.push(char::from(b'0' + hundreds));
+    }
+    if hundreds > 0 || tens > 0 {
+        buf.push(char::from(b'0' + tens));
+    }
+    buf.push(char::from(b'0' + ones));
+}
+
+/// Appends a 0.0-1.0 alpha rounded to three places, without trailing zeros
+fn push_alpha(buf: &mut String, alpha: f32) {
+    let thousandths = (alpha.clamp(0.0, 1.0) * 1000.0 + 0.5) as u16;
+    match thousandths {
+        0 => buf.push('0'),
+        1000.. => buf.push('1'),
+        _ => {
+            buf.push_str("0.");
+            let mut digits = thousandths;
+            let mut divisor = 100;
+            while digits > 0 {
+                let digit = digits / divisor;
+                buf.push(char::from(b'0' + digit as u8));
+                digits -= digit * divisor;
+                divisor /= 10;
+            }
+        }
+    }
+}
+
+/// Default color is fully opaque black
+impl Default for Color {
+    fn default() -> Self {
+        Color::new(0.0, 0.0, 0.0, 1.0)
+    }
 }
 
 /// Implementation of animation interpolation for Color
@@ -136,6 +427,20 @@ impl Animatable for Color {
 
         Color::new(r, g, b, a)
     }
+
+    fn interpolate_in(&self, target: &Self, t: f32, space: ColorSpace) -> Self {
+        Color::interpolate_in(self, target, t, space)
+    }
+
+    /// Bounds each RGBA channel separately
+    fn map_within(&self, min: &Self, max: &Self, f: &dyn Fn(f32, f32, f32) -> f32) -> Self {
+        Color::new(
+            f(self.r, min.r, max.r),
+            f(self.g, min.g, max.g),
+            f(self.b, min.b, max.b),
+            f(self.a, min.a, max.a),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -181,4 +486,102 @@ mod tests {
         assert_eq!(b, 0);
         assert_eq!(a, 255);
     }
+
+    #[test]
+    fn test_color_alpha_fades_independently() {
+        let opaque = Color::from_rgba(255, 128, 0, 255);
+        let transparent = Color { a: 0.0, ..opaque };
+
+        let mid = opaque.interpolate(&transparent, 0.5);
+        assert_eq!(mid.to_rgba(), (255, 128, 0, 128));
+        assert_eq!(mid.to_css_rgba(), "rgba(255, 128, 0, 0.5)");
+        assert_eq!(opaque.interpolate(&transparent, 1.0), transparent);
+        assert_eq!(Color::default().a, 1.0);
+    }
+
+    #[test]
+    fn test_color_css_formatting() {
+        let color = Color::from_rgba(8, 64, 200, 255);
+        assert_eq!(color.to_css_rgb(), "rgb(8, 64, 200)");
+        assert_eq!(color.to_css_rgba(), "rgba(8, 64, 200, 1)");
+
+        // Alpha keeps up to three places and drops trailing zeros
+        let alpha = |a| Color { a, ..color }.to_css_rgba();
+        assert_eq!(alpha(0.0), "rgba(8, 64, 200, 0)");
+        assert_eq!(alpha(0.25), "rgba(8, 64, 200, 0.25)");
+        assert_eq!(alpha(0.0504), "rgba(8, 64, 200, 0.05)");
+        assert_eq!(alpha(0.12345), "rgba(8, 64, 200, 0.123)");
+        assert_eq!(alpha(0.9996), "rgba(8, 64, 200, 1)");
+
+        // Appends to what is already there
+        let mut css = String::from("color: ");
+        Color::from_rgba(0, 10, 100, 0).write_css(&mut css);
+        assert_eq!(css, "color: rgba(0, 10, 100, 0)");
+    }
+
+    #[test]
+    fn test_color_hex_round_trip() {
+        for hex in ["#ff8000", "#000000", "#3b82f6", "#a855f780", "#ffffff00"] {
+            let color = Color::from_hex(hex).expect("valid hex color");
+            assert_eq!(color.to_hex(), hex);
+        }
+        assert_eq!(Color::from_hex("#F80"), Color::from_hex("#ff8800"));
+        assert_eq!(
+            Color::from_hex("#a855f780").map(|color| color.to_rgba()),
+            Ok((168, 85, 247, 128))
+        );
+    }
+
+    #[test]
+    fn test_color_hex_rejects_malformed_input() {
+        assert_eq!(
+            Color::from_hex("#ggg"),
+            Err(ColorParseError::InvalidDigit('g'))
+        );
+        assert_eq!(Color::from_hex("ff8000"), Err(ColorParseError::MissingHash));
+        assert_eq!(
+            Color::from_hex("#ff80"),
+            Err(ColorParseError::InvalidLength(4))
+        );
+        assert_eq!(Color::from_hex("#"), Err(ColorParseError::InvalidLength(0)));
+    }
+
+    #[test]
+    fn test_color_hsl_passes_through_yellow() {
+        let red = Color::new(1.0, 0.0, 0.0, 1.0);
+        let green = Color::new(0.0, 1.0, 0.0, 1.0);
+        let mid = red.interpolate_in(&green, 0.5, ColorSpace::Hsl);
+
+        assert!((mid.r - 1.0).abs() < 0.0001);
+        assert!((mid.g - 1.0).abs() < 0.0001);
+        assert!(mid.b.abs() < 0.0001);
+
+        // RGB goes through a muddy dark olive instead
+        let rgb_mid = red.interpolate_in(&green, 0.5, ColorSpace::Rgb);
+        assert!((rgb_mid.r - 0.5).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_color_hsl_takes_shorter_hue_arc() {
+        // Magenta (300) to orange (30) should wrap through red (0) rather than cyan, meeting at 345
+        let magenta = Color::new(1.0, 0.0, 1.0, 1.0);
+        let orange = Color::new(1.0, 0.5, 0.0, 1.0);
+        let (hue, _, _) = magenta
+            .interpolate_in(&orange, 0.5, ColorSpace::Hsl)
+            .to_hsl();
+
+        assert!((hue - 345.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_color_hsl_round_trip() {
+        let color = Color::new(0.2, 0.6, 0.9, 0.5);
+        let (h, s, l) = color.to_hsl();
+        let back = Color::from_hsl(h, s, l, color.a);
+
+        assert!((back.r - color.r).abs() < 0.0001);
+        assert!((back.g - color.g).abs() < 0.0001);
+        assert!((back.b - color.b).abs() < 0.0001);
+        assert_eq!(back.a, color.a);
+    }
 }
@@ -0,0 +1,95 @@
+//! Decay animation module
+//!
+//! Provides momentum animations that glide to a stop under friction, for flings and
+//! inertial scrolling. Where a decay comes to rest is decided by its starting velocity
+//! rather than a preset target.
+
+use instant::Duration;
+
+use crate::animations::utils::Animatable;
+
+/// Configuration for decay (momentum) animations
+///
+/// Velocity falls off exponentially, `v(t) = v0 * e^(-friction * t)`, so a value
+/// starting at `v0` travels `v0 / friction` in total before it comes to rest.
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::prelude::Decay;
+/// let decay = Decay::new(4.0);
+/// assert_eq!(decay.projected_distance(800.0f32), 200.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Decay {
+    /// How quickly velocity bleeds away, per second (default: 2.0)
+    /// Higher values stop sooner and travel less far
+    pub friction: f32,
+}
+
+/// Default decay, close to the normal scroll deceleration on iOS
+impl Default for Decay {
+    fn default() -> Self {
+        Self { friction: 2.0 }
+    }
+}
+
+impl Decay {
+    /// Creates a decay with the given friction
+    pub fn new(friction: f32) -> Self {
+        Self { friction }
+    }
+
+    /// Distance a value starting at `velocity` covers before coming to rest
+    pub fn projected_distance<T: Animatable>(&self, velocity: T) -> T {
+        velocity.scale(1.0 / self.friction())
+    }
+
+    /// Rough time for the velocity to drop below 2% of where it started
+    pub fn estimated_settling_time(&self) -> Duration {
+        Duration::try_from_secs_f32(4.0 / self.friction()).unwrap_or(Duration::MAX)
+    }
+
+    /// Advances `velocity` by `dt` seconds, returning the distance covered and the new velocity
+    pub(crate) fn step<T: Animatable>(&self, velocity: T, dt: f32) -> (T, T) {
+        let friction = self.friction();
+        let falloff = (-friction * dt).exp();
+        (
+            velocity.scale((1.0 - falloff) / friction),
+            velocity.scale(falloff),
+        )
+    }
+
+    /// Friction kept above zero, so a frictionless decay still ends eventually
+    fn friction(&self) -> f32 {
+        self.friction.max(f32::EPSILON)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decay_steps_add_up_to_projected_distance() {
+        let decay = Decay::default();
+        let mut velocity = 500.0f32;
+        let mut travelled = 0.0;
+        for _ in 0..1000 {
+            let (distance, next) = decay.step(velocity, 1.0 / 60.0);
+            travelled += distance;
+            velocity = next;
+        }
+
+        assert!(velocity.abs() < 1e-3);
+        assert!((travelled - decay.projected_distance(500.0f32)).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_decay_higher_friction_travels_less() {
+        let gentle = Decay::new(1.0);
+        let firm = Decay::new(8.0);
+        assert!(firm.projected_distance(300.0f32) < gentle.projected_distance(300.0f32));
+        assert!(firm.estimated_settling_time() < gentle.estimated_settling_time());
+    }
+}
@@ -0,0 +1,201 @@
+//! Length module for CSS lengths
+//!
+//! Provides a Length type that carries its CSS unit, so a value meant as a
+//! percentage can't be mistaken for pixels when it is written into a style.
+//! Lengths animate within their unit and format with [`Length::to_css`].
+
+use crate::animations::utils::{Animatable, snap_to_grid};
+
+/// Unit of a [`Length`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LengthUnit {
+    /// CSS pixels, `px`
+    #[default]
+    Px,
+    /// Percentage of the containing block or the element itself, depending on the property
+    Percent,
+    /// Multiples of the root font size, `rem`
+    Rem,
+    /// Percentage of the viewport height, `vh`
+    Vh,
+    /// Percentage of the viewport width, `vw`
+    Vw,
+}
+
+impl LengthUnit {
+    /// The unit's CSS suffix
+    pub fn as_css(&self) -> &'static str {
+        match self {
+            Self::Px => "px",
+            Self::Percent => "%",
+            Self::Rem => "rem",
+            Self::Vh => "vh",
+            Self::Vw => "vw",
+        }
+    }
+}
+
+/// A CSS length: a number and its unit
+///
+/// Animating between lengths of the same unit interpolates the number. Lengths in
+/// different units can't be converted without a layout, so a tween between them jumps to
+/// the target halfway through, like a discrete CSS property, and springs move the number
+/// in the starting unit and switch when they settle. Zero counts as any unit, so
+/// `Length::zero()` animates smoothly into a length of any unit.
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::prelude::Length;
+/// let offset = Length::percent(50.0);
+/// assert_eq!(offset.to_css(), "50%");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Length {
+    /// The number, in `unit`
+    pub value: f32,
+    /// The unit the number is in
+    pub unit: LengthUnit,
+}
+
+impl Length {
+    /// Creates a length from a number and its unit
+    pub fn new(value: f32, unit: LengthUnit) -> Self {
+        Self { value, unit }
+    }
+
+    /// Creates a length in pixels
+    pub fn px(value: f32) -> Self {
+        Self::new(value, LengthUnit::Px)
+    }
+
+    /// Creates a percentage
+    pub fn percent(value: f32) -> Self {
+        Self::new(value, LengthUnit::Percent)
+    }
+
+    /// Creates a length in root font sizes
+    pub fn rem(value: f32) -> Self {
+        Self::new(value, LengthUnit::Rem)
+    }
+
+    /// Creates a length in percent of the viewport height
+    pub fn vh(value: f32) -> Self {
+        Self::new(value, LengthUnit::Vh)
+    }
+
+    /// Creates a length in percent of the viewport width
+    pub fn vw(value: f32) -> Self {
+        Self::new(value, LengthUnit::Vw)
+    }
+
+    /// Formats the length as a CSS value, such as `12px` or `50%`
+    pub fn to_css(&self) -> String {
+        format!("{}{}", self.value, self.unit.as_css())
+    }
+
+    /// Unit of a result combining `self` with `other`, taking the other unit when this is zero
+    fn unit_with(&self, other: &Self) -> LengthUnit {
+        if self.value == 0.0 {
+            other.unit
+        } else {
+            self.unit
+        }
+    }
+
+    /// Whether the two lengths can be interpolated without switching units
+    fn compatible(&self, other: &Self) -> bool {
+        self.unit == other.unit || self.value == 0.0 || other.value == 0.0
+    }
+}
+
+/// Implementation of Animatable for Length
+/// Works on the number, keeping the unit of whichever side isn't zero
+impl Animatable for Length {
+    /// Zero pixels, which takes on the unit of whatever it's combined with
+    fn zero() -> Self {
+        Length::px(0.0)
+    }
+
+    /// Same threshold as a single float
+    fn epsilon() -> f32 {
+        f32::epsilon()
+    }
+
+    /// Size of the number, whatever the unit
+    fn magnitude(&self) -> f32 {
+        self.value.abs()
+    }
+
+    /// Scales the number, keeping the unit
+    fn scale(&self, factor: f32) -> Self {
+        Length::new(self.value * factor, self.unit)
+    }
+
+    /// Adds the numbers
+    fn add(&self, other: &Self) -> Self {
+        Length::new(self.value + other.value, self.unit_with(other))
+    }
+
+    /// Subtracts the numbers
+    fn sub(&self, other: &Self) -> Self {
+        Length::new(self.value - other.value, self.unit_with(other))
+    }
+
+    /// Linearly interpolates lengths of one unit, and jumps halfway between different units
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        if !self.compatible(target) {
+            return if t < 0.5 { *self } else { *target };
+        }
+        Length::new(
+            self.value + (target.value - self.value) * t,
+            self.unit_with(target),
+        )
+    }
+
+    /// Bounds the number, treating `min` and `max` as being in this length's unit
+    fn map_within(&self, min: &Self, max: &Self, f: &dyn Fn(f32, f32, f32) -> f32) -> Self {
+        Length::new(f(self.value, min.value, max.value), self.unit)
+    }
+
+    fn snap(&self, grid: f32) -> Self {
+        Length::new(snap_to_grid(self.value, grid), self.unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_length_to_css() {
+        assert_eq!(Length::px(12.0).to_css(), "12px");
+        assert_eq!(Length::percent(50.0).to_css(), "50%");
+        assert_eq!(Length::rem(1.5).to_css(), "1.5rem");
+        assert_eq!(Length::vh(100.0).to_css(), "100vh");
+        assert_eq!(Length::vw(-25.0).to_css(), "-25vw");
+    }
+
+    #[test]
+    fn test_length_interpolates_within_its_unit() {
+        let start = Length::percent(0.0);
+        let end = Length::percent(100.0);
+        assert_eq!(start.interpolate(&end, 0.25), Length::percent(25.0));
+
+        // Zero takes on the other unit, so the value can start from nothing
+        assert_eq!(
+            Length::zero().interpolate(&Length::rem(2.0), 0.5),
+            Length::rem(1.0)
+        );
+        assert_eq!(Length::vh(10.0).sub(&Length::zero()), Length::vh(10.0));
+    }
+
+    #[test]
+    fn test_length_jumps_between_units() {
+        let start = Length::px(10.0);
+        let end = Length::percent(50.0);
+        assert_eq!(start.interpolate(&end, 0.4), start);
+        assert_eq!(start.interpolate(&end, 0.6), end);
+    }
+}
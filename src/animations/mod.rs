@@ -1,6 +1,11 @@
 pub mod colors;
+pub mod decay;
+pub mod length;
 pub mod platform;
+pub mod rotation;
 pub mod spring;
 pub mod transform;
+pub mod tuple;
 pub mod tween;
 pub mod utils;
+pub mod vector;
@@ -4,7 +4,18 @@
 //! Supports both web (WASM) and native platforms.
 
 use instant::{Duration, Instant};
+use std::cell::{Cell, RefCell};
 use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::task::{Poll, Waker};
+
+/// App-wide reduced motion switch, see [`set_reduced_motion`]
+static REDUCED_MOTION: AtomicBool = AtomicBool::new(false);
+
+/// App-wide speed multiplier as `f32` bits, see [`set_motion_config`]
+static GLOBAL_SPEED: AtomicU32 = AtomicU32::new(1.0f32.to_bits());
+/// App-wide animation switch, see [`set_motion_config`]
+static ANIMATIONS_ENABLED: AtomicBool = AtomicBool::new(true);
 
 /// Provides platform-agnostic timing operations
 ///
@@ -27,6 +38,10 @@ pub trait TimeProvider {
 pub struct MotionTime;
 
 impl TimeProvider for MotionTime {
+    /// Returns the current instant
+    ///
+    /// On web, `instant` reads `performance.now()`, the browser's monotonic clock with
+    /// sub-millisecond resolution, rather than the wall clock.
     fn now() -> Instant {
         Instant::now()
     }
@@ -104,3 +119,185 @@ impl TimeProvider for MotionTime {
 
 /// Type alias for the default time provider
 pub type Time = MotionTime;
+
+/// Manually driven clock for deterministic tests
+///
+/// Time only moves when [`TestTime::advance`] is called, and delays resolve once enough
+/// simulated time has passed, so tests can step animations frame by frame without sleeping.
+/// The clock belongs to the current thread, so tests running in parallel don't disturb
+/// each other, but it has to be advanced on the thread that reads it. Plug it into a motion
+/// with [`Motion::with_time_provider`](crate::Motion::with_time_provider) so drag and lead
+/// timeouts follow it too.
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::{Duration, TestTime, TimeProvider};
+/// let start = TestTime::now();
+/// TestTime::advance(Duration::from_millis(16));
+/// assert_eq!(TestTime::now() - start, Duration::from_millis(16));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TestTime;
+
+struct TestClock {
+    start: Instant,
+    elapsed: Cell<Duration>,
+    waiting: RefCell<Vec<Waker>>,
+}
+
+thread_local! {
+    static TEST_CLOCK: TestClock = TestClock {
+        start: Instant::now(),
+        elapsed: Cell::new(Duration::ZERO),
+        waiting: RefCell::new(Vec::new()),
+    };
+}
+
+impl TestTime {
+    /// Moves this thread's clock forward, waking any delay that may now be due
+    pub fn advance(by: Duration) {
+        let waiting = TEST_CLOCK.with(|clock| {
+            clock.elapsed.set(clock.elapsed.get().saturating_add(by));
+            std::mem::take(&mut *clock.waiting.borrow_mut())
+        });
+        for waker in waiting {
+            waker.wake();
+        }
+    }
+
+    /// Total simulated time this thread's clock has been advanced by
+    pub fn elapsed() -> Duration {
+        TEST_CLOCK.with(|clock| clock.elapsed.get())
+    }
+}
+
+impl TimeProvider for TestTime {
+    fn now() -> Instant {
+        TEST_CLOCK.with(|clock| clock.start + clock.elapsed.get())
+    }
+
+    /// Resolves once the clock has been advanced past `duration` from now
+    fn delay(duration: Duration) -> impl Future<Output = ()> {
+        let deadline = Self::elapsed().saturating_add(duration);
+        std::future::poll_fn(move |cx| {
+            if Self::elapsed() >= deadline {
+                Poll::Ready(())
+            } else {
+                TEST_CLOCK.with(|clock| clock.waiting.borrow_mut().push(cx.waker().clone()));
+                Poll::Pending
+            }
+        })
+    }
+}
+
+/// Settings that apply to every animation in the app, see [`set_motion_config`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MotionConfig {
+    /// Speed multiplier for every animation (default: 1.0)
+    ///
+    /// Multiplies each animation's own `AnimationConfig::speed` rather than replacing it:
+    /// an animation at speed 2.0 under a global speed of 0.5 plays at normal speed.
+    pub global_speed: f32,
+    /// When false, animations jump straight to their end and fire their callbacks, as
+    /// with `finish` (default: true)
+    ///
+    /// Takes precedence over any speed, for "disable animations" settings and end-to-end
+    /// tests that need transitions to be instant.
+    pub animations_enabled: bool,
+}
+
+impl Default for MotionConfig {
+    fn default() -> Self {
+        Self {
+            global_speed: 1.0,
+            animations_enabled: true,
+        }
+    }
+}
+
+/// Replaces the app-wide [`MotionConfig`], taking effect on the next frame
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::{MotionConfig, motion_config, set_motion_config};
+/// set_motion_config(MotionConfig {
+///     animations_enabled: false,
+///     ..MotionConfig::default()
+/// });
+/// assert!(!motion_config().animations_enabled);
+/// ```
+pub fn set_motion_config(config: MotionConfig) {
+    GLOBAL_SPEED.store(config.global_speed.max(0.0).to_bits(), Ordering::Relaxed);
+    ANIMATIONS_ENABLED.store(config.animations_enabled, Ordering::Relaxed);
+}
+
+/// The app-wide [`MotionConfig`] currently in effect
+pub fn motion_config() -> MotionConfig {
+    MotionConfig {
+        global_speed: f32::from_bits(GLOBAL_SPEED.load(Ordering::Relaxed)),
+        animations_enabled: ANIMATIONS_ENABLED.load(Ordering::Relaxed),
+    }
+}
+
+/// Turns reduced motion on or off for the whole app
+///
+/// Useful on platforms without a system preference to query, or to offer an in-app
+/// setting. On web the `prefers-reduced-motion` media query is honored as well.
+pub fn set_reduced_motion(reduce: bool) {
+    REDUCED_MOTION.store(reduce, Ordering::Relaxed);
+}
+
+/// Whether animations that opt in via `respect_reduced_motion` should skip to their end
+///
+/// True when [`set_reduced_motion`] turned it on, or on web when the browser matches
+/// `(prefers-reduced-motion: reduce)`.
+pub fn prefers_reduced_motion() -> bool {
+    REDUCED_MOTION.load(Ordering::Relaxed) || system_prefers_reduced_motion()
+}
+
+#[cfg(feature = "web")]
+fn system_prefers_reduced_motion() -> bool {
+    web_sys::window()
+        .and_then(|window| {
+            window
+                .match_media("(prefers-reduced-motion: reduce)")
+                .ok()
+                .flatten()
+        })
+        .is_some_and(|query| query.matches())
+}
+
+#[cfg(not(feature = "web"))]
+fn system_prefers_reduced_motion() -> bool {
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::pin::pin;
+    use std::task::Context;
+
+    #[test]
+    fn test_test_time_only_moves_when_advanced() {
+        let start = TestTime::now();
+        assert_eq!(TestTime::now(), start);
+
+        TestTime::advance(Duration::from_millis(10));
+        TestTime::advance(Duration::from_millis(6));
+        assert_eq!(TestTime::now() - start, Duration::from_millis(16));
+    }
+
+    #[test]
+    fn test_test_time_delay_resolves_after_advance() {
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut delay = pin!(TestTime::delay(Duration::from_millis(100)));
+        assert!(delay.as_mut().poll(&mut cx).is_pending());
+
+        TestTime::advance(Duration::from_millis(99));
+        assert!(delay.as_mut().poll(&mut cx).is_pending());
+
+        TestTime::advance(Duration::from_millis(1));
+        assert!(delay.as_mut().poll(&mut cx).is_ready());
+    }
+}
@@ -0,0 +1,294 @@
+//! Rotation module for 3D orientations
+//!
+//! Provides a quaternion-backed Rotation3D type that can be animated.
+//! Tweens use spherical linear interpolation (slerp), so rotations follow the
+//! shortest arc without the gimbal lock and odd paths of interpolating Euler angles.
+
+use crate::animations::utils::Animatable;
+
+/// Represents a 3D orientation as a unit quaternion
+///
+/// Angles passed to the constructors are in degrees, matching CSS `rotateX`/`rotateY`/`rotateZ`.
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::prelude::Rotation3D;
+/// let rotation = Rotation3D::from_axis_angle(0.0, 1.0, 0.0, 90.0);
+/// let style = format!("transform: {}", rotation.to_css_matrix3d());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rotation3D {
+    /// X component of the rotation axis, scaled by `sin(angle / 2)`
+    pub x: f32,
+    /// Y component of the rotation axis, scaled by `sin(angle / 2)`
+    pub y: f32,
+    /// Z component of the rotation axis, scaled by `sin(angle / 2)`
+    pub z: f32,
+    /// Scalar part, `cos(angle / 2)`
+    pub w: f32,
+}
+
+impl Rotation3D {
+    /// Creates the rotation that leaves everything in place
+    pub fn identity() -> Self {
+        Self {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+            w: 1.0,
+        }
+    }
+
+    /// Creates a rotation of `degrees` around the axis `(x, y, z)`
+    ///
+    /// The axis doesn't need to be normalized. Angles beyond ±180° describe the same
+    /// orientation as the shorter turn the other way, and are stored as such.
+    pub fn from_axis_angle(x: f32, y: f32, z: f32, degrees: f32) -> Self {
+        let length = (x * x + y * y + z * z).sqrt();
+        if length == 0.0 {
+            return Self::identity();
+        }
+        let (sin, cos) = (degrees.to_radians() / 2.0).sin_cos();
+        let factor = sin / length;
+        Self {
+            x: x * factor,
+            y: y * factor,
+            z: z * factor,
+            w: cos,
+        }
+        .canonical()
+    }
+
+    /// Creates the rotation applied by CSS `rotateX(x) rotateY(y) rotateZ(z)`, in degrees
+    pub fn from_euler(x: f32, y: f32, z: f32) -> Self {
+        Self::from_axis_angle(1.0, 0.0, 0.0, x)
+            .multiply(&Self::from_axis_angle(0.0, 1.0, 0.0, y))
+            .multiply(&Self::from_axis_angle(0.0, 0.0, 1.0, z))
+            .canonical()
+    }
+
+    /// Returns the same rotation scaled to unit length
+    pub fn normalized(&self) -> Self {
+        let length = self.magnitude();
+        if length == 0.0 {
+            Self::identity()
+        } else {
+            self.scale(1.0 / length)
+        }
+    }
+
+    /// Rotation matrix in the column-major order used by CSS `matrix3d`
+    pub fn to_matrix3d(&self) -> [f32; 16] {
+        let Self { x, y, z, w } = self.normalized();
+        [
+            1.0 - 2.0 * (y * y + z * z),
+            2.0 * (x * y + z * w),
+            2.0 * (x * z - y * w),
+            0.0,
+            2.0 * (x * y - z * w),
+            1.0 - 2.0 * (x * x + z * z),
+            2.0 * (y * z + x * w),
+            0.0,
+            2.0 * (x * z + y * w),
+            2.0 * (y * z - x * w),
+            1.0 - 2.0 * (x * x + y * y),
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            1.0,
+        ]
+    }
+
+    /// Formats the rotation as a CSS `matrix3d(...)` transform function
+    pub fn to_css_matrix3d(&self) -> String {
+        let values = self.to_matrix3d().map(|value| value.to_string());
+        format!("matrix3d({})", values.join(", "))
+    }
+
+    /// Hamilton product, the same as listing `self` before `other` in a CSS transform
+    fn multiply(&self, other: &Self) -> Self {
+        Self {
+            x: self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            y: self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            z: self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+            w: self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z,
+        }
+    }
+
+    fn dot(&self, other: &Self) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    /// `q` and `-q` are the same rotation; keeping `w` non-negative makes springs
+    /// between constructed rotations head for the nearby representation
+    fn canonical(self) -> Self {
+        if self.w < 0.0 { self.scale(-1.0) } else { self }
+    }
+}
+
+/// Default rotation is the identity rotation
+impl Default for Rotation3D {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+/// Implementation of Animatable for Rotation3D
+/// Springs work on the raw quaternion components and are normalized on output,
+/// while tweens slerp along the shortest arc
+impl Animatable for Rotation3D {
+    /// Creates an all-zero quaternion, used as the rest velocity rather than a rotation
+    fn zero() -> Self {
+        Self {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+            w: 0.0,
+        }
+    }
+
+    /// Minimum meaningful difference between rotations
+    fn epsilon() -> f32 {
+        0.001
+    }
+
+    /// Length of the quaternion as a 4D vector
+    fn magnitude(&self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    /// Scales every component by a factor
+    fn scale(&self, factor: f32) -> Self {
+        Self {
+            x: self.x * factor,
+            y: self.y * factor,
+            z: self.z * factor,
+            w: self.w * factor,
+        }
+    }
+
+    /// Adds two quaternions component-wise
+    fn add(&self, other: &Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+            w: self.w + other.w,
+        }
+    }
+
+    /// Subtracts two quaternions component-wise
+    fn sub(&self, other: &Self) -> Self {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+            w: self.w - other.w,
+        }
+    }
+
+    /// Spherical linear interpolation along the shortest arc
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        let start = self.normalized();
+        let mut end = target.normalized();
+
+        // Flip to the representation on the same hemisphere for the short way round
+        let mut cos_theta = start.dot(&end);
+        if cos_theta < 0.0 {
+            end = end.scale(-1.0);
+            cos_theta = -cos_theta;
+        }
+
+        // Nearly identical rotations: fall back to a normalized lerp to avoid dividing by ~0
+        if cos_theta > 0.9995 {
+            return start.add(&end.sub(&start).scale(t)).normalized();
+        }
+
+        let theta = cos_theta.acos();
+        let sin_theta = theta.sin();
+        let start_weight = ((1.0 - t) * theta).sin() / sin_theta;
+        let end_weight = (t * theta).sin() / sin_theta;
+        start.scale(start_weight).add(&end.scale(end_weight))
+    }
+
+    /// Bounds the raw quaternion components, like the springs that step them
+    fn map_within(&self, min: &Self, max: &Self, f: &dyn Fn(f32, f32, f32) -> f32) -> Self {
+        Self {
+            x: f(self.x, min.x, max.x),
+            y: f(self.y, min.y, max.y),
+            z: f(self.z, min.z, max.z),
+            w: f(self.w, min.w, max.w),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: Rotation3D, b: Rotation3D) {
+        assert!(a.sub(&b).magnitude() < 1e-5, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn test_rotation_identity() {
+        assert_eq!(Rotation3D::default(), Rotation3D::identity());
+        assert_close(
+            Rotation3D::from_axis_angle(0.0, 0.0, 0.0, 90.0),
+            Rotation3D::identity(),
+        );
+        assert_close(
+            Rotation3D::from_euler(0.0, 0.0, 0.0),
+            Rotation3D::identity(),
+        );
+    }
+
+    #[test]
+    fn test_rotation_slerp_takes_short_path() {
+        let start = Rotation3D::identity();
+        let end = Rotation3D::from_axis_angle(0.0, 1.0, 0.0, 270.0);
+        let mid = start.interpolate(&end, 0.5);
+
+        // 270° one way is 90° the other, so halfway is -45° rather than 135°
+        assert_close(mid, Rotation3D::from_axis_angle(0.0, 1.0, 0.0, -45.0));
+        assert_close(start.interpolate(&end, 1.0), end);
+    }
+
+    #[test]
+    fn test_rotation_slerp_keeps_unit_length() {
+        let start = Rotation3D::from_euler(30.0, 0.0, 0.0);
+        let end = Rotation3D::from_euler(0.0, 120.0, 45.0);
+        for step in 0..=10 {
+            let value = start.interpolate(&end, step as f32 / 10.0);
+            assert!((value.magnitude() - 1.0).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_rotation_css_matrix() {
+        // rotateY(90deg) maps x to -z and z to x
+        let matrix = Rotation3D::from_axis_angle(0.0, 1.0, 0.0, 90.0).to_matrix3d();
+        let expected = [
+            0.0, 0.0, -1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+        ];
+        for (value, expected) in matrix.iter().zip(expected) {
+            assert!((value - expected).abs() < 1e-6);
+        }
+
+        assert_eq!(
+            Rotation3D::identity().to_css_matrix3d(),
+            "matrix3d(1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1)"
+        );
+    }
+
+    #[test]
+    fn test_rotation_euler_matches_css_order() {
+        // rotateX(90deg) rotateY(90deg) turns the y axis onto z
+        let matrix = Rotation3D::from_euler(90.0, 90.0, 0.0).to_matrix3d();
+        let y_axis = [matrix[4], matrix[5], matrix[6]];
+        assert!(y_axis[0].abs() < 1e-6 && y_axis[1].abs() < 1e-6);
+        assert!((y_axis[2] - 1.0).abs() < 1e-6);
+    }
+}
@@ -3,6 +3,8 @@
 //! Provides a physical spring model for smooth, natural-looking animations.
 //! Based on Hooke's law with damping for realistic motion.
 
+use instant::Duration;
+
 /// Configuration for spring-based animations
 ///
 /// Uses a mass-spring-damper system to create natural motion.
@@ -18,6 +20,7 @@
 /// };
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Spring {
     /// Spring stiffness constant (default: 100.0)
     /// Higher values make the spring stronger and faster
@@ -48,6 +51,222 @@ impl Default for Spring {
     }
 }
 
+impl Spring {
+    /// Creates a spring that settles in about `duration` with the given `bounce`
+    ///
+    /// Follows the same mapping as SwiftUI's spring API, with mass fixed at 1.0:
+    /// - angular frequency `ω = 2π / duration`
+    /// - damping ratio `ζ = 1 - bounce`
+    /// - `stiffness = ω²` and `damping = 2ζω`
+    ///
+    /// A bounce of 0.0 is critically damped and never overshoots, higher values
+    /// overshoot more. Bounce is clamped to `0.0..=0.95` so the spring always settles.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::Duration;
+    /// use dioxus_motion::prelude::Spring;
+    /// let spring = Spring::with_duration(Duration::from_millis(500), 0.3);
+    /// ```
+    pub fn with_duration(duration: Duration, bounce: f32) -> Self {
+        let bounce = bounce.clamp(0.0, 0.95);
+        let omega = 2.0 * std::f32::consts::PI / duration.as_secs_f32().max(f32::EPSILON);
+        let damping_ratio = 1.0 - bounce;
+
+        Self {
+            stiffness: omega * omega,
+            damping: 2.0 * damping_ratio * omega,
+            mass: 1.0,
+            velocity: 0.0,
+        }
+    }
+}
+
+/// Analytic properties of the spring, computed without running it
+impl Spring {
+    /// Damping ratio `ζ = damping / (2 * sqrt(stiffness * mass))`
+    ///
+    /// Below 1.0 the spring is underdamped and overshoots its target, at exactly 1.0 it is
+    /// critically damped, and above 1.0 it is overdamped and creeps in without overshooting.
+    pub fn damping_ratio(&self) -> f32 {
+        self.damping / (2.0 * (self.stiffness * self.mass).sqrt())
+    }
+
+    /// True when the spring approaches its target without ever overshooting (`ζ > 1`)
+    pub fn is_overdamped(&self) -> bool {
+        self.damping_ratio() > 1.0
+    }
+
+    /// Rough time for the spring to settle within 2% of its target, starting from rest
+    ///
+    /// With natural frequency `ω = sqrt(stiffness / mass)`, the motion decays like
+    /// `e^(-λt)` where `λ = ζω` when underdamped and `λ = ω(ζ - sqrt(ζ² - 1))` (the slower
+    /// of the two exponentials) otherwise. Settling takes about `4 / λ`, since `e^-4 ≈ 2%`.
+    /// A spring without damping never settles and returns `Duration::MAX`.
+    pub fn estimated_settling_time(&self) -> Duration {
+        let omega = (self.stiffness / self.mass).sqrt();
+        let zeta = self.damping_ratio();
+        let decay_rate = if zeta < 1.0 {
+            zeta * omega
+        } else {
+            omega * (zeta - (zeta * zeta - 1.0).sqrt())
+        };
+
+        Duration::try_from_secs_f32(4.0 / decay_rate).unwrap_or(Duration::MAX)
+    }
+
+    /// Total mechanical energy `½·m·v² + ½·k·x²` of the spring when it is `displacement`
+    /// away from its target and moving at `speed`
+    ///
+    /// The kinetic part uses the mass and the potential part the stiffness, so the same
+    /// motion carries more energy on a heavier or stiffer spring.
+    pub fn energy(&self, displacement: f32, speed: f32) -> f32 {
+        0.5 * self.mass * speed * speed + 0.5 * self.stiffness * displacement * displacement
+    }
+}
+
+/// Exact stepping of the spring, used by [`SpringSolver::Analytic`]
+impl Spring {
+    /// How displacement from the target and velocity evolve over `dt` seconds
+    ///
+    /// Solves `m·x'' + c·x' + k·x = 0` in closed form. The result is a matrix `[[a, b], [c, d]]`
+    /// such that after `dt` the displacement is `a·x0 + b·v0` and the velocity is `c·x0 + d·v0`,
+    /// so any `Animatable` can be stepped with `scale` and `add`.
+    pub(crate) fn analytic_step(&self, dt: f32) -> [[f32; 2]; 2] {
+        let omega = (self.stiffness / self.mass).sqrt();
+        if !omega.is_finite() || omega <= 0.0 {
+            // No restoring force, the value just coasts
+            return [[1.0, dt], [0.0, 1.0]];
+        }
+        let zeta = self.damping_ratio();
+
+        if (zeta - 1.0).abs() < 1e-4 {
+            // Critically damped: x(t) = e^(-ωt) * (x0 + (v0 + ω·x0)·t)
+            let decay = (-omega * dt).exp();
+            [
+                [decay * (1.0 + omega * dt), decay * dt],
+                [-decay * omega * omega * dt, decay * (1.0 - omega * dt)],
+            ]
+        } else if zeta < 1.0 {
+            // Underdamped: a decaying oscillation at the damped frequency
+            let damped = omega * (1.0 - zeta * zeta).sqrt();
+            let decay = (-zeta * omega * dt).exp();
+            let (sin, cos) = (damped * dt).sin_cos();
+            [
+                [
+                    decay * (cos + zeta * omega / damped * sin),
+                    decay * sin / damped,
+                ],
+                [
+                    -decay * omega * omega / damped * sin,
+                    decay * (cos - zeta * omega / damped * sin),
+                ],
+            ]
+        } else {
+            // Overdamped: the sum of a fast and a slow exponential
+            let root = omega * (zeta * zeta - 1.0).sqrt();
+            let (slow, fast) = (-zeta * omega + root, -zeta * omega - root);
+            let (slow_decay, fast_decay) = ((slow * dt).exp(), (fast * dt).exp());
+            let spread = slow - fast;
+            [
+                [
+                    (slow * fast_decay - fast * slow_decay) / spread,
+                    (slow_decay - fast_decay) / spread,
+                ],
+                [
+                    slow * fast * (fast_decay - slow_decay) / spread,
+                    (slow * slow_decay - fast * fast_decay) / spread,
+                ],
+            ]
+        }
+    }
+}
+
+/// Named presets matching the feel of common JS animation libraries
+impl Spring {
+    /// Soft and slightly bouncy, settles in roughly 0.6s
+    pub const fn gentle() -> Self {
+        Self {
+            stiffness: 120.0,
+            damping: 14.0,
+            mass: 1.0,
+            velocity: 0.0,
+        }
+    }
+
+    /// Lively with a few visible oscillations, settles in roughly 0.7s
+    pub const fn wobbly() -> Self {
+        Self {
+            stiffness: 180.0,
+            damping: 12.0,
+            mass: 1.0,
+            velocity: 0.0,
+        }
+    }
+
+    /// Snappy with a small overshoot, settles in roughly 0.4s
+    pub const fn stiff() -> Self {
+        Self {
+            stiffness: 210.0,
+            damping: 20.0,
+            mass: 1.0,
+            velocity: 0.0,
+        }
+    }
+
+    /// Overdamped with no overshoot, creeps in over roughly 0.8s
+    pub const fn slow() -> Self {
+        Self {
+            stiffness: 280.0,
+            damping: 60.0,
+            mass: 1.0,
+            velocity: 0.0,
+        }
+    }
+}
+
+/// How spring animations are advanced each frame
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpringSolver {
+    /// Numerical integration split into small substeps
+    #[default]
+    Integrated,
+    /// Exact closed-form solution of the spring for the frame's time, without substeps
+    ///
+    /// Cheaper on slow devices and gives the same motion however long the frames are.
+    Analytic,
+}
+
+/// When a spring animation counts as finished
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpringCompletion {
+    /// Both the distance to the target and the speed fall below the completion epsilon
+    ///
+    /// The safe choice: a fast spring passing through its target keeps going, and a
+    /// bouncy one momentarily still at the end of a swing swings back. The cost is that a
+    /// spring that has nearly stopped short of its target, as overdamped springs do, keeps
+    /// crawling until it is within the epsilon.
+    #[default]
+    Thresholds,
+    /// Either the distance to the target or the speed falls below the completion epsilon
+    ///
+    /// The spring then jumps the rest of the way, so overdamped springs stop as soon as
+    /// they have nearly stopped rather than crawling into place. Low speed only counts while
+    /// the spring is closing in on the target, so a spring starting from rest still sets
+    /// off. Bouncy springs can still be cut short as they pass through the target, or just
+    /// after turning at the end of a swing, so this suits well damped springs.
+    AnyThreshold,
+    /// The spring's total energy, see [`Spring::energy`], falls below this amount
+    ///
+    /// Motion is settled as a whole rather than waiting for distance and speed separately,
+    /// cutting the long tails of soft springs crawling into place. With a stiffness of 100,
+    /// a threshold of 1.0 stops the spring once it is within about 0.14 of its target and
+    /// nearly still.
+    Energy(f32),
+}
+
 /// Represents the current state of a spring animation
 ///
 /// Used to track whether the spring is still moving or has settled
@@ -63,6 +282,20 @@ pub enum SpringState {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_spring_energy() {
+        let spring = Spring {
+            stiffness: 100.0,
+            damping: 10.0,
+            mass: 2.0,
+            velocity: 0.0,
+        };
+        assert_eq!(spring.energy(0.0, 0.0), 0.0);
+        assert_eq!(spring.energy(1.0, 0.0), 50.0);
+        assert_eq!(spring.energy(0.0, 3.0), 9.0);
+        assert_eq!(spring.energy(1.0, 3.0), 59.0);
+    }
+
     #[test]
     fn test_spring_default() {
         let spring = Spring::default();
@@ -86,4 +319,97 @@ mod tests {
         assert_eq!(spring.mass, 2.0);
         assert_eq!(spring.velocity, 5.0);
     }
+
+    #[test]
+    fn test_spring_with_duration() {
+        let spring = Spring::with_duration(Duration::from_secs(1), 0.0);
+        let omega = 2.0 * std::f32::consts::PI;
+
+        assert!((spring.stiffness - omega * omega).abs() < 1e-3);
+        // Zero bounce is critically damped: damping == 2 * sqrt(stiffness * mass)
+        assert!((spring.damping - 2.0 * (spring.stiffness * spring.mass).sqrt()).abs() < 1e-3);
+
+        let bouncy = Spring::with_duration(Duration::from_secs(1), 0.5);
+        assert!(bouncy.damping < spring.damping);
+        assert_eq!(bouncy.stiffness, spring.stiffness);
+    }
+
+    #[test]
+    fn test_spring_damping_ratio() {
+        assert!((Spring::default().damping_ratio() - 0.5).abs() < 1e-6);
+        assert!(!Spring::default().is_overdamped());
+        assert!(Spring::slow().is_overdamped());
+
+        let critical = Spring::with_duration(Duration::from_secs(1), 0.0);
+        assert!((critical.damping_ratio() - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_spring_settling_time() {
+        // Underdamped: 4 / (ζω) = 8 * mass / damping
+        let settle = Spring::default().estimated_settling_time().as_secs_f32();
+        assert!((settle - 0.8).abs() < 1e-4);
+
+        // Stiffer springs settle faster, heavier damping past critical slows them down
+        assert!(
+            Spring::stiff().estimated_settling_time() < Spring::default().estimated_settling_time()
+        );
+        let overdamped = Spring {
+            damping: 200.0,
+            ..Spring::slow()
+        };
+        assert!(overdamped.estimated_settling_time() > Spring::slow().estimated_settling_time());
+
+        let undamped = Spring {
+            damping: 0.0,
+            ..Spring::default()
+        };
+        assert_eq!(undamped.estimated_settling_time(), Duration::MAX);
+    }
+
+    #[test]
+    fn test_spring_analytic_step_composes() {
+        // Stepping twice by dt must equal one step of 2·dt, whatever the damping
+        for spring in [
+            Spring::default(),
+            Spring::slow(),
+            Spring::with_duration(Duration::from_secs(1), 0.0),
+        ] {
+            let [[a, b], [c, d]] = spring.analytic_step(0.05);
+            let twice = [
+                [a * a + b * c, a * b + b * d],
+                [c * a + d * c, c * b + d * d],
+            ];
+            let once = spring.analytic_step(0.1);
+            for (row, expected) in twice.iter().zip(once) {
+                for (value, expected) in row.iter().zip(expected) {
+                    assert!((value - expected).abs() < 1e-4);
+                }
+            }
+        }
+
+        // At dt = 0 nothing moves
+        assert_eq!(
+            Spring::default().analytic_step(0.0),
+            [[1.0, 0.0], [0.0, 1.0]]
+        );
+    }
+
+    #[test]
+    fn test_spring_presets() {
+        const GENTLE: Spring = Spring::gentle();
+        assert_eq!((GENTLE.stiffness, GENTLE.damping), (120.0, 14.0));
+
+        let wobbly = Spring::wobbly();
+        let stiff = Spring::stiff();
+        let slow = Spring::slow();
+
+        // Wobbly bounces more than stiff, slow is the most damped
+        assert!(wobbly.damping < stiff.damping);
+        assert!(slow.damping > stiff.damping);
+        for preset in [GENTLE, wobbly, stiff, slow] {
+            assert_eq!(preset.mass, 1.0);
+            assert_eq!(preset.velocity, 0.0);
+        }
+    }
 }
@@ -4,12 +4,20 @@
 //! - Translation (x, y)
 //! - Scale
 //! - Rotation
+//! - Skew
+//! - 3D rotation around the x, y and z axes
 //!
-//! Uses radians for rotation and supports smooth interpolation.
+//! Uses radians for rotation and skew, degrees for the 3D rotations,
+//! and supports smooth interpolation.
 
 use crate::Animatable;
+use crate::animations::utils::snap_to_grid;
 
-/// Represents a 2D transformation with translation, scale, and rotation
+/// Represents a 2D transformation with translation, scale, rotation, and skew
+///
+/// Translation has no unit of its own: [`Transform::to_css_transform`] writes it in pixels
+/// and [`Transform::to_css_transform_percent`] in percent. Animate a
+/// [`Length`](crate::prelude::Length) for an offset that carries its unit.
 ///
 /// # Examples
 /// ```rust
@@ -18,6 +26,7 @@ use crate::Animatable;
 /// let transform = Transform::new(100.0, 50.0, 1.5, PI/4.0);
 /// ```
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Transform {
     /// X translation component
     pub x: f32,
@@ -27,27 +36,116 @@ pub struct Transform {
     pub scale: f32,
     /// Rotation in radians
     pub rotation: f32,
+    /// Skew along the X axis in radians
+    pub skew_x: f32,
+    /// Skew along the Y axis in radians
+    pub skew_y: f32,
+    /// Rotation around the X axis in degrees
+    pub rotate_x: f32,
+    /// Rotation around the Y axis in degrees
+    pub rotate_y: f32,
+    /// Rotation around the Z axis in degrees
+    pub rotate_z: f32,
 }
 
 impl Transform {
-    /// Creates a new transform with specified parameters
+    /// Creates a new transform with specified parameters and no skew or 3D rotation
     pub fn new(x: f32, y: f32, scale: f32, rotation: f32) -> Self {
         Self {
             x,
             y,
             scale,
             rotation,
+            skew_x: 0.0,
+            skew_y: 0.0,
+            rotate_x: 0.0,
+            rotate_y: 0.0,
+            rotate_z: 0.0,
         }
     }
 
     /// Creates an identity transform (no transformation)
     pub fn identity() -> Self {
-        Self {
-            x: 0.0,
-            y: 0.0,
-            scale: 1.0,
-            rotation: 0.0,
-        }
+        Self::new(0.0, 0.0, 1.0, 0.0)
+    }
+
+    /// Creates a transform that only translates, to be extended with the `with_*` builders
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::Transform;
+    /// let transform = Transform::translate(20.0, -10.0).with_scale(1.2);
+    /// ```
+    pub fn translate(x: f32, y: f32) -> Self {
+        Self::new(x, y, 1.0, 0.0)
+    }
+
+    /// Sets the uniform scale factor
+    pub fn with_scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Sets the rotation in radians
+    pub fn with_rotation(mut self, rotation: f32) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Sets the skew angles in radians
+    pub fn with_skew(mut self, skew_x: f32, skew_y: f32) -> Self {
+        self.skew_x = skew_x;
+        self.skew_y = skew_y;
+        self
+    }
+
+    /// Sets the 3D rotations around the x, y and z axes in degrees
+    pub fn with_rotation_3d(mut self, rotate_x: f32, rotate_y: f32, rotate_z: f32) -> Self {
+        self.rotate_x = rotate_x;
+        self.rotate_y = rotate_y;
+        self.rotate_z = rotate_z;
+        self
+    }
+}
+
+/// CSS output
+impl Transform {
+    /// Formats the transform as a CSS `transform` value with translation in pixels
+    ///
+    /// Produces `translate3d(xpx, ypx, 0) scale(s) rotate(r rad) skew(sx rad, sy rad)`
+    /// followed by `rotateX(..deg) rotateY(..deg) rotateZ(..deg)`, keeping the units
+    /// of the fields themselves.
+    pub fn to_css_transform(&self) -> String {
+        self.css_transform("px")
+    }
+
+    /// Same as [`Transform::to_css_transform`], but translation is a percentage of the
+    /// element's own size, as used by page transitions
+    pub fn to_css_transform_percent(&self) -> String {
+        self.css_transform("%")
+    }
+
+    fn css_transform(&self, unit: &str) -> String {
+        format!(
+            "translate3d({}{unit}, {}{unit}, 0) scale({}) rotate({}rad) skew({}rad, {}rad) \
+             rotateX({}deg) rotateY({}deg) rotateZ({}deg)",
+            self.x,
+            self.y,
+            self.scale,
+            self.rotation,
+            self.skew_x,
+            self.skew_y,
+            self.rotate_x,
+            self.rotate_y,
+            self.rotate_z
+        )
+    }
+}
+
+/// Default transform is the identity transform
+impl Default for Transform {
+    fn default() -> Self {
+        Self::identity()
     }
 }
 
@@ -81,6 +179,63 @@ impl Animatable for f32 {
     fn interpolate(&self, target: &Self, t: f32) -> Self {
         self + (target - self) * t
     }
+
+    fn map_within(&self, min: &Self, max: &Self, f: &dyn Fn(f32, f32, f32) -> f32) -> Self {
+        f(*self, *min, *max)
+    }
+
+    fn snap(&self, grid: f32) -> Self {
+        snap_to_grid(*self, grid)
+    }
+}
+
+/// Implementation of Animatable for f64 primitive type
+/// For values that need more than f32's ~7 significant digits, like map coordinates.
+/// The value is kept in full precision, while progress, spring factors and the magnitudes
+/// used for completion checks stay f32, so springs still settle within 0.001 by default;
+/// use `AnimationConfig::with_completion_epsilon` for finer resolution. Mixing an f64 with
+/// f32 types such as `Transform` in one tuple limits those components to f32 precision.
+impl Animatable for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn epsilon() -> f32 {
+        0.000_001
+    }
+
+    fn magnitude(&self) -> f32 {
+        self.abs() as f32
+    }
+
+    fn scale(&self, factor: f32) -> Self {
+        self * f64::from(factor)
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        self + other
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        self - other
+    }
+
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        self + (target - self) * f64::from(t)
+    }
+
+    fn map_within(&self, min: &Self, max: &Self, f: &dyn Fn(f32, f32, f32) -> f32) -> Self {
+        if (*min..=*max).contains(self) {
+            return *self;
+        }
+        // Map the offset from `min`, which keeps f32 precision for narrow ranges far from zero
+        min + f64::from(f((self - min) as f32, 0.0, (max - min) as f32))
+    }
+
+    fn snap(&self, grid: f32) -> Self {
+        let grid = f64::from(grid);
+        (self / grid).round() * grid
+    }
 }
 
 /// Implementation of Animatable for Transform
@@ -101,7 +256,12 @@ impl Animatable for Transform {
         (self.x * self.x
             + self.y * self.y
             + self.scale * self.scale
-            + self.rotation * self.rotation)
+            + self.rotation * self.rotation
+            + self.skew_x * self.skew_x
+            + self.skew_y * self.skew_y
+            + self.rotate_x * self.rotate_x
+            + self.rotate_y * self.rotate_y
+            + self.rotate_z * self.rotate_z)
             .sqrt()
     }
 
@@ -113,6 +273,12 @@ impl Animatable for Transform {
             self.scale * factor,
             self.rotation * factor,
         )
+        .with_skew(self.skew_x * factor, self.skew_y * factor)
+        .with_rotation_3d(
+            self.rotate_x * factor,
+            self.rotate_y * factor,
+            self.rotate_z * factor,
+        )
     }
 
     /// Adds two transforms component-wise
@@ -123,6 +289,12 @@ impl Animatable for Transform {
             self.scale + other.scale,
             self.rotation + other.rotation,
         )
+        .with_skew(self.skew_x + other.skew_x, self.skew_y + other.skew_y)
+        .with_rotation_3d(
+            self.rotate_x + other.rotate_x,
+            self.rotate_y + other.rotate_y,
+            self.rotate_z + other.rotate_z,
+        )
     }
 
     /// Subtracts two transforms component-wise
@@ -133,10 +305,18 @@ impl Animatable for Transform {
             self.scale - other.scale,
             self.rotation - other.rotation,
         )
+        .with_skew(self.skew_x - other.skew_x, self.skew_y - other.skew_y)
+        .with_rotation_3d(
+            self.rotate_x - other.rotate_x,
+            self.rotate_y - other.rotate_y,
+            self.rotate_z - other.rotate_z,
+        )
     }
 
     /// Interpolates between two transforms
     /// Handles rotation specially to ensure shortest path
+    /// The 3D rotations are interpolated linearly in degrees, one axis at a time, with no
+    /// quaternion slerp, so a 0° to 360° spin really turns all the way around.
     fn interpolate(&self, target: &Self, t: f32) -> Self {
         // Special handling for rotation to ensure shortest path
         let mut rotation_diff = target.rotation - self.rotation;
@@ -152,6 +332,38 @@ impl Animatable for Transform {
             self.scale + (target.scale - self.scale) * t,
             self.rotation + rotation_diff * t,
         )
+        .with_skew(
+            self.skew_x + (target.skew_x - self.skew_x) * t,
+            self.skew_y + (target.skew_y - self.skew_y) * t,
+        )
+        .with_rotation_3d(
+            self.rotate_x + (target.rotate_x - self.rotate_x) * t,
+            self.rotate_y + (target.rotate_y - self.rotate_y) * t,
+            self.rotate_z + (target.rotate_z - self.rotate_z) * t,
+        )
+    }
+
+    fn map_within(&self, min: &Self, max: &Self, f: &dyn Fn(f32, f32, f32) -> f32) -> Self {
+        Transform::new(
+            f(self.x, min.x, max.x),
+            f(self.y, min.y, max.y),
+            f(self.scale, min.scale, max.scale),
+            f(self.rotation, min.rotation, max.rotation),
+        )
+        .with_skew(
+            f(self.skew_x, min.skew_x, max.skew_x),
+            f(self.skew_y, min.skew_y, max.skew_y),
+        )
+        .with_rotation_3d(
+            f(self.rotate_x, min.rotate_x, max.rotate_x),
+            f(self.rotate_y, min.rotate_y, max.rotate_y),
+            f(self.rotate_z, min.rotate_z, max.rotate_z),
+        )
+    }
+
+    /// Turns the translation a quarter turn; every other component is zero
+    fn perpendicular(&self) -> Self {
+        Transform::new(-self.y, self.x, 0.0, 0.0)
     }
 }
 
@@ -169,6 +381,24 @@ mod tests {
         assert!((transform.rotation - PI / 4.0).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn test_f64_keeps_precision_far_from_zero() {
+        let start = 1_000_000.000_001f64;
+        let end = 1_000_000.000_003f64;
+        let mid = start.interpolate(&end, 0.5);
+        assert!((mid - 1_000_000.000_002).abs() < 1e-9);
+        assert_eq!(start.interpolate(&end, 1.0), end);
+
+        // Bounds work on offsets, so clamping doesn't round to f32
+        let clamp = |v: f32, min: f32, max: f32| v.max(min).min(max);
+        assert_eq!(
+            end.map_within(&start, &mid, &clamp),
+            start + (mid - start) as f32 as f64
+        );
+        assert_eq!(0.0f64.map_within(&start, &end, &clamp), start);
+        assert_eq!(mid.map_within(&start, &end, &clamp), mid);
+    }
+
     #[test]
     fn test_transform_default() {
         let transform = Transform::identity();
@@ -176,6 +406,13 @@ mod tests {
         assert_eq!(transform.y, 0.0);
         assert_eq!(transform.scale, 1.0);
         assert_eq!(transform.rotation, 0.0);
+        assert_eq!(transform.skew_x, 0.0);
+        assert_eq!(transform.skew_y, 0.0);
+        assert_eq!(
+            (transform.rotate_x, transform.rotate_y, transform.rotate_z),
+            (0.0, 0.0, 0.0)
+        );
+        assert_eq!(Transform::default(), transform);
     }
 
     #[test]
@@ -189,4 +426,68 @@ mod tests {
         assert_eq!(mid.scale, 1.5);
         assert!((mid.rotation - PI / 2.0).abs() < f32::EPSILON);
     }
+
+    #[test]
+    fn test_transform_scale_can_flip() {
+        let start = Transform::identity();
+        let mirrored = Transform::new(0.0, 0.0, -1.0, 0.0);
+
+        // A flip passes through zero to a mirrored scale
+        assert_eq!(start.interpolate(&mirrored, 0.5).scale, 0.0);
+        assert_eq!(start.interpolate(&mirrored, 1.0).scale, -1.0);
+    }
+
+    #[test]
+    fn test_transform_builders() {
+        let transform = Transform::translate(20.0, -10.0)
+            .with_scale(1.5)
+            .with_rotation(PI);
+        assert_eq!(transform, Transform::new(20.0, -10.0, 1.5, PI));
+        assert_eq!(Transform::translate(0.0, 0.0), Transform::identity());
+    }
+
+    #[test]
+    fn test_transform_css() {
+        let transform = Transform::translate(20.0, -10.5)
+            .with_scale(2.0)
+            .with_skew(0.5, 0.0);
+        assert_eq!(
+            transform.to_css_transform(),
+            "translate3d(20px, -10.5px, 0) scale(2) rotate(0rad) skew(0.5rad, 0rad) \
+             rotateX(0deg) rotateY(0deg) rotateZ(0deg)"
+        );
+        assert_eq!(
+            Transform::identity()
+                .with_rotation_3d(10.0, -20.0, 0.0)
+                .to_css_transform_percent(),
+            "translate3d(0%, 0%, 0) scale(1) rotate(0rad) skew(0rad, 0rad) \
+             rotateX(10deg) rotateY(-20deg) rotateZ(0deg)"
+        );
+    }
+
+    #[test]
+    fn test_transform_rotation_3d_is_linear() {
+        let start = Transform::identity();
+        let end = Transform::identity().with_rotation_3d(90.0, 360.0, -30.0);
+        let mid = start.interpolate(&end, 0.5);
+
+        // Unlike `rotation`, a full turn isn't folded into the shortest path
+        assert_eq!(
+            (mid.rotate_x, mid.rotate_y, mid.rotate_z),
+            (45.0, 180.0, -15.0)
+        );
+        assert_eq!(end.sub(&start).add(&start), end);
+        assert_eq!(end.scale(0.5).rotate_y, 180.0);
+    }
+
+    #[test]
+    fn test_transform_skew() {
+        let start = Transform::identity();
+        let end = Transform::identity().with_skew(PI / 4.0, -PI / 8.0);
+        let mid = start.interpolate(&end, 0.5);
+
+        assert!((mid.skew_x - PI / 8.0).abs() < f32::EPSILON);
+        assert!((mid.skew_y + PI / 16.0).abs() < f32::EPSILON);
+        assert!((end.sub(&start).magnitude() - (PI / 4.0).hypot(PI / 8.0)).abs() < 1e-6);
+    }
 }
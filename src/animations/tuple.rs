@@ -0,0 +1,101 @@
+//! Tuple module for bundles of values
+//!
+//! Tuples of up to four animatable values animate as one value, so a bundle such as
+//! `(offset, opacity)` stays in step through interruptions without separate signals.
+
+use crate::animations::{colors::ColorSpace, utils::Animatable};
+
+/// Implements Animatable component-wise for a tuple of animatable types
+macro_rules! impl_animatable_for_tuple {
+    ($($name:ident : $index:tt),+) => {
+        impl<$($name: Animatable),+> Animatable for ($($name,)+) {
+            /// Creates a tuple of zero values
+            fn zero() -> Self {
+                ($($name::zero(),)+)
+            }
+
+            /// Smallest threshold of any member, so no member stops short
+            fn epsilon() -> f32 {
+                [$($name::epsilon()),+].into_iter().fold(f32::INFINITY, f32::min)
+            }
+
+            /// Euclidean norm of the members' magnitudes
+            fn magnitude(&self) -> f32 {
+                (0.0 $(+ self.$index.magnitude().powi(2))+).sqrt()
+            }
+
+            /// Scales every member by a factor
+            fn scale(&self, factor: f32) -> Self {
+                ($(self.$index.scale(factor),)+)
+            }
+
+            /// Adds two tuples member-wise
+            fn add(&self, other: &Self) -> Self {
+                ($(self.$index.add(&other.$index),)+)
+            }
+
+            /// Subtracts two tuples member-wise
+            fn sub(&self, other: &Self) -> Self {
+                ($(self.$index.sub(&other.$index),)+)
+            }
+
+            /// Interpolates every member with its own interpolation
+            fn interpolate(&self, target: &Self, t: f32) -> Self {
+                ($(self.$index.interpolate(&target.$index, t),)+)
+            }
+
+            /// Passes the color space on, so colors in a tuple still honor it
+            fn interpolate_in(&self, target: &Self, t: f32, space: ColorSpace) -> Self {
+                ($(self.$index.interpolate_in(&target.$index, t, space),)+)
+            }
+
+            /// Bounds every member that supports bounds
+            fn map_within(
+                &self,
+                min: &Self,
+                max: &Self,
+                f: &dyn Fn(f32, f32, f32) -> f32,
+            ) -> Self {
+                ($(self.$index.map_within(&min.$index, &max.$index, f),)+)
+            }
+
+            fn snap(&self, grid: f32) -> Self {
+                ($(self.$index.snap(grid),)+)
+            }
+
+            fn perpendicular(&self) -> Self {
+                ($(self.$index.perpendicular(),)+)
+            }
+        }
+    };
+}
+
+impl_animatable_for_tuple!(A: 0, B: 1);
+impl_animatable_for_tuple!(A: 0, B: 1, C: 2);
+impl_animatable_for_tuple!(A: 0, B: 1, C: 2, D: 3);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animations::{colors::Color, vector::Vector2D};
+
+    #[test]
+    fn test_tuple_arithmetic() {
+        let a = (1.0f32, Vector2D::new(2.0, 3.0));
+        let b = (4.0f32, Vector2D::new(6.0, 3.0));
+
+        assert_eq!(a.add(&b), (5.0, Vector2D::new(8.0, 6.0)));
+        assert_eq!(b.sub(&a), (3.0, Vector2D::new(4.0, 0.0)));
+        assert_eq!(a.scale(2.0), (2.0, Vector2D::new(4.0, 6.0)));
+        assert_eq!(a.interpolate(&b, 0.5), (2.5, Vector2D::new(4.0, 3.0)));
+        // |3| and |(4, 0)| combine like the sides of a right triangle
+        assert_eq!(b.sub(&a).magnitude(), 5.0);
+    }
+
+    #[test]
+    fn test_tuple_epsilon_is_smallest_member() {
+        assert_eq!(<(f32, f32, f32, f32)>::zero(), (0.0, 0.0, 0.0, 0.0));
+        assert_eq!(<(f32, Vector2D)>::epsilon(), f32::epsilon());
+        assert_eq!(<(f32, Color)>::epsilon(), Color::epsilon());
+    }
+}
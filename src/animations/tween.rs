@@ -3,9 +3,221 @@
 //! Provides time-based animation with customizable easing functions.
 //! Supports duration and interpolation control for smooth animations.
 
-use easer::functions::{Easing, Linear};
+use std::{fmt, sync::Arc};
+
+use easer::functions::{Easing as _, Linear};
 pub use instant::Duration;
 
+/// Easing curve used by a tween
+///
+/// Takes the same `(t, b, c, d)` arguments as the classic easing function pointers,
+/// which convert into it with `.into()`, but can also hold a closure with captured
+/// state such as the control points of [`Tween::bezier`].
+///
+/// With the `serde` feature, easings built from a [`NamedEasing`] (including
+/// `Tween::bezier` and `Tween::from_samples`) serialize as that name. Arbitrary closures
+/// have no name to write out, so serializing them fails.
+#[derive(Clone)]
+pub struct Easing {
+    curve: Arc<dyn Fn(f32, f32, f32, f32) -> f32 + Send + Sync>,
+    named: Option<Arc<NamedEasing>>,
+}
+
+impl Easing {
+    /// Wraps a closure taking `(t, b, c, d)` as an easing curve
+    pub fn new(f: impl Fn(f32, f32, f32, f32) -> f32 + Send + Sync + 'static) -> Self {
+        Self {
+            curve: Arc::new(f),
+            named: None,
+        }
+    }
+
+    /// Evaluates the curve at time `t` for a change from `b` to `b + c` over duration `d`
+    pub fn ease(&self, t: f32, b: f32, c: f32, d: f32) -> f32 {
+        (self.curve)(t, b, c, d)
+    }
+
+    /// The named curve this easing was built from, if any
+    pub fn named(&self) -> Option<&NamedEasing> {
+        self.named.as_deref()
+    }
+}
+
+/// Easing curves that can be referred to by name, e.g. in presets loaded from a file
+///
+/// Converts into an [`Easing`] with `.into()`, so it can be passed to
+/// [`Tween::with_easing`] directly.
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::Duration;
+/// use dioxus_motion::prelude::{NamedEasing, Tween};
+/// let tween = Tween::new(Duration::from_millis(400)).with_easing(NamedEasing::EaseOutBack);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NamedEasing {
+    /// Constant speed
+    #[default]
+    Linear,
+    /// CSS `ease`, `cubic-bezier(0.25, 0.1, 0.25, 1.0)`
+    Ease,
+    /// CSS `ease-in`, `cubic-bezier(0.42, 0.0, 1.0, 1.0)`
+    EaseIn,
+    /// CSS `ease-out`, `cubic-bezier(0.0, 0.0, 0.58, 1.0)`
+    EaseOut,
+    /// CSS `ease-in-out`, `cubic-bezier(0.42, 0.0, 0.58, 1.0)`
+    EaseInOut,
+    /// See [`ease_in_quad`]
+    EaseInQuad,
+    /// See [`ease_out_quad`]
+    EaseOutQuad,
+    /// See [`ease_in_out_cubic`]
+    EaseInOutCubic,
+    /// See [`ease_out_back`]
+    EaseOutBack,
+    /// See [`ease_in_out_elastic`]
+    EaseInOutElastic,
+    /// See [`ease_out_bounce`]
+    EaseOutBounce,
+    /// A CSS-style `cubic-bezier(x1, y1, x2, y2)`, see [`Tween::bezier`]
+    CubicBezier(f32, f32, f32, f32),
+    /// Progress sampled at evenly spaced times, see [`Tween::from_samples`]
+    Samples(Vec<f32>),
+    /// Jumps in this many equal intervals, holding each level, like CSS `steps(n, jump)`
+    ///
+    /// For sprite sheets and ticking counters. Zero steps is treated as one, and
+    /// [`JumpMode::None`] needs at least two.
+    Steps(u32, JumpMode),
+}
+
+/// Where the jumps of a [`NamedEasing::Steps`] easing fall, matching the jump terms of
+/// CSS `steps()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum JumpMode {
+    /// Jumps at the start of each interval, so the first level is already a step in
+    Start,
+    /// Jumps at the end of each interval, reaching the target only as the tween finishes
+    #[default]
+    End,
+    /// Starts at the initial value and ends at the target, with a jump between each interval
+    None,
+    /// Jumps at both ends, so neither the start nor the target is held
+    Both,
+}
+
+impl JumpMode {
+    /// Progress after `steps` equal intervals at `progress` (0.0 to 1.0)
+    fn level(self, steps: u32, progress: f32) -> f32 {
+        let steps = steps.max(if self == Self::None { 2 } else { 1 });
+        let (offset, jumps) = match self {
+            Self::Start => (1, steps),
+            Self::End => (0, steps),
+            Self::None => (0, steps - 1),
+            Self::Both => (1, steps + 1),
+        };
+        // Whole intervals elapsed, by division so no modulo is needed
+        let step = (progress.clamp(0.0, 1.0) * steps as f32).floor() as u32 + offset;
+        step.min(jumps) as f32 / jumps as f32
+    }
+}
+
+impl From<NamedEasing> for Easing {
+    fn from(named: NamedEasing) -> Self {
+        let mut easing = match &named {
+            NamedEasing::Linear => Easing::new(Linear::ease_in_out),
+            NamedEasing::Ease => bezier_easing(0.25, 0.1, 0.25, 1.0),
+            NamedEasing::EaseIn => bezier_easing(0.42, 0.0, 1.0, 1.0),
+            NamedEasing::EaseOut => bezier_easing(0.0, 0.0, 0.58, 1.0),
+            NamedEasing::EaseInOut => bezier_easing(0.42, 0.0, 0.58, 1.0),
+            NamedEasing::EaseInQuad => Easing::new(ease_in_quad),
+            NamedEasing::EaseOutQuad => Easing::new(ease_out_quad),
+            NamedEasing::EaseInOutCubic => Easing::new(ease_in_out_cubic),
+            NamedEasing::EaseOutBack => Easing::new(ease_out_back),
+            NamedEasing::EaseInOutElastic => Easing::new(ease_in_out_elastic),
+            NamedEasing::EaseOutBounce => Easing::new(ease_out_bounce),
+            NamedEasing::CubicBezier(x1, y1, x2, y2) => bezier_easing(*x1, *y1, *x2, *y2),
+            NamedEasing::Samples(samples) => sampled_easing(samples.clone()),
+            NamedEasing::Steps(steps, jump) => {
+                let (steps, jump) = (*steps, *jump);
+                Easing::new(move |t, b, c, d| b + c * jump.level(steps, t / d))
+            }
+        };
+        easing.named = Some(Arc::new(named));
+        easing
+    }
+}
+
+/// CSS-style `cubic-bezier` curve
+fn bezier_easing(x1: f32, y1: f32, x2: f32, y2: f32) -> Easing {
+    let curve = CubicBezier::new(x1, y1, x2, y2);
+    Easing::new(move |t, b, c, d| b + c * curve.solve(t / d))
+}
+
+/// Piecewise-linear curve through evenly spaced progress samples, linear with fewer than two
+fn sampled_easing(samples: Vec<f32>) -> Easing {
+    if samples.len() < 2 {
+        return Easing::new(Linear::ease_in_out);
+    }
+    let segments = (samples.len() - 1) as f32;
+    Easing::new(move |t, b, c, d| {
+        let position = (t / d).clamp(0.0, 1.0) * segments;
+        let index = (position as usize).min(samples.len() - 2);
+        let (from, to) = (samples[index], samples[index + 1]);
+        b + c * (from + (to - from) * (position - index as f32))
+    })
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Easing {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::Error;
+        self.named()
+            .ok_or_else(|| {
+                S::Error::custom("only easings built from a NamedEasing can be serialized")
+            })?
+            .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Easing {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        NamedEasing::deserialize(deserializer).map(Easing::from)
+    }
+}
+
+impl<F> From<F> for Easing
+where
+    F: Fn(f32, f32, f32, f32) -> f32 + Send + Sync + 'static,
+{
+    fn from(f: F) -> Self {
+        Self::new(f)
+    }
+}
+
+impl Default for Easing {
+    fn default() -> Self {
+        NamedEasing::Linear.into()
+    }
+}
+
+impl fmt::Debug for Easing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Easing")
+    }
+}
+
+/// Two easings are equal if they share the same underlying curve, or were built from
+/// the same [`NamedEasing`]
+impl PartialEq for Easing {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.curve, &other.curve)
+            || (self.named.is_some() && self.named == other.named)
+    }
+}
+
 /// Configuration for tween-based animations
 ///
 /// # Examples
@@ -16,12 +228,13 @@ pub use instant::Duration;
 /// let tween = Tween::new(Duration::from_secs(1))
 ///     .with_easing(easer::functions::Cubic::ease_in_out);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Tween {
     /// Duration of the animation
     pub duration: Duration,
     /// Easing function for interpolation
-    pub easing: fn(f32, f32, f32, f32) -> f32,
+    pub easing: Easing,
 }
 
 /// Default tween configuration with 300ms duration and linear easing
@@ -29,7 +242,7 @@ impl Default for Tween {
     fn default() -> Self {
         Self {
             duration: Duration::from_millis(300),
-            easing: Linear::ease_in_out,
+            easing: Easing::default(),
         }
     }
 }
@@ -39,30 +252,222 @@ impl Tween {
     pub fn new(duration: Duration) -> Self {
         Self {
             duration,
-            easing: Linear::ease_in_out,
+            easing: Easing::default(),
+        }
+    }
+
+    /// Creates a tween that follows a CSS-style `cubic-bezier(x1, y1, x2, y2)` curve
+    ///
+    /// The x coordinates are clamped to 0.0..=1.0 as in CSS, so the curve stays a
+    /// function of time. The y coordinates may leave that range to overshoot.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::Duration;
+    /// use dioxus_motion::prelude::Tween;
+    /// let ease = Tween::bezier(0.25, 0.1, 0.25, 1.0).with_duration(Duration::from_millis(400));
+    /// ```
+    pub fn bezier(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Self {
+            easing: NamedEasing::CubicBezier(x1, y1, x2, y2).into(),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a tween whose easing follows a table of sampled progress values
+    ///
+    /// The samples are spread evenly over the tween, the first at its start and the last at
+    /// its end, with straight lines in between, e.g. for replaying a curve exported from a
+    /// design tool. Samples may leave 0.0..=1.0 to overshoot. With fewer than two samples
+    /// there is no curve to follow and the tween stays linear.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::Tween;
+    /// let settle = Tween::from_samples(vec![0.0, 0.6, 0.9, 1.05, 1.0]);
+    /// ```
+    pub fn from_samples(samples: Vec<f32>) -> Self {
+        if samples.len() < 2 {
+            return Self::default();
         }
+        Self {
+            easing: NamedEasing::Samples(samples).into(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the duration of the animation
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
     }
 
     /// Sets the easing function for the animation
     ///
     /// # Arguments
     /// * `easing` - Function that takes (t, b, c, d) and returns interpolated value
-    pub fn with_easing(mut self, easing: fn(f32, f32, f32, f32) -> f32) -> Self {
-        self.easing = easing;
+    pub fn with_easing(mut self, easing: impl Into<Easing>) -> Self {
+        self.easing = easing.into();
         self
     }
 }
 
+/// Cubic bezier from (0, 0) to (1, 1) with two control points, as used by CSS
+#[derive(Debug, Clone, Copy)]
+struct CubicBezier {
+    x1: f32,
+    y1: f32,
+    x2: f32,
+    y2: f32,
+}
+
+impl CubicBezier {
+    fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Self {
+            x1: x1.clamp(0.0, 1.0),
+            y1,
+            x2: x2.clamp(0.0, 1.0),
+            y2,
+        }
+    }
+
+    /// One coordinate of the curve at parameter `t` given its two control values
+    fn sample(t: f32, p1: f32, p2: f32) -> f32 {
+        let u = 1.0 - t;
+        3.0 * u * u * t * p1 + 3.0 * u * t * t * p2 + t * t * t
+    }
+
+    /// Derivative of [`Self::sample`] with respect to `t`
+    fn slope(t: f32, p1: f32, p2: f32) -> f32 {
+        let u = 1.0 - t;
+        3.0 * u * u * p1 + 6.0 * u * t * (p2 - p1) + 3.0 * t * t * (1.0 - p2)
+    }
+
+    /// Returns the curve's y for a given x (progress) in 0.0..=1.0
+    fn solve(&self, x: f32) -> f32 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        if x >= 1.0 {
+            return 1.0;
+        }
+
+        // Newton's method converges in a few steps for most curves
+        let mut t = x;
+        for _ in 0..8 {
+            let error = Self::sample(t, self.x1, self.x2) - x;
+            if error.abs() < 1e-6 {
+                return Self::sample(t, self.y1, self.y2);
+            }
+            let slope = Self::slope(t, self.x1, self.x2);
+            if slope.abs() < 1e-6 {
+                break;
+            }
+            t -= error / slope;
+        }
+
+        // Fall back to bisection where the slope is too flat for Newton
+        let (mut low, mut high) = (0.0, 1.0);
+        t = x;
+        for _ in 0..32 {
+            let sampled = Self::sample(t, self.x1, self.x2);
+            if (sampled - x).abs() < 1e-6 {
+                break;
+            }
+            if sampled < x {
+                low = t;
+            } else {
+                high = t;
+            }
+            t = (low + high) / 2.0;
+        }
+        Self::sample(t, self.y1, self.y2)
+    }
+}
+
+/// Quadratic ease-in: starts slow and accelerates
+pub fn ease_in_quad(t: f32, b: f32, c: f32, d: f32) -> f32 {
+    let t = t / d;
+    c * t * t + b
+}
+
+/// Quadratic ease-out: starts fast and decelerates
+pub fn ease_out_quad(t: f32, b: f32, c: f32, d: f32) -> f32 {
+    let t = t / d;
+    -c * t * (t - 2.0) + b
+}
+
+/// Cubic ease-in-out: accelerates through the first half and decelerates through the second
+pub fn ease_in_out_cubic(t: f32, b: f32, c: f32, d: f32) -> f32 {
+    let t = t / (d / 2.0);
+    if t < 1.0 {
+        c / 2.0 * t * t * t + b
+    } else {
+        let t = t - 2.0;
+        c / 2.0 * (t * t * t + 2.0) + b
+    }
+}
+
+/// Back ease-out: overshoots the end by about 10% before settling
+pub fn ease_out_back(t: f32, b: f32, c: f32, d: f32) -> f32 {
+    const OVERSHOOT: f32 = 1.70158;
+    if t <= 0.0 {
+        return b;
+    }
+    let t = t / d - 1.0;
+    c * (t * t * ((OVERSHOOT + 1.0) * t + OVERSHOOT) + 1.0) + b
+}
+
+/// Elastic ease-in-out: winds up like a spring, then snaps past the end and oscillates in
+pub fn ease_in_out_elastic(t: f32, b: f32, c: f32, d: f32) -> f32 {
+    if t <= 0.0 {
+        return b;
+    }
+    let t = t / (d / 2.0);
+    if t >= 2.0 {
+        return b + c;
+    }
+    let period = d * (0.3 * 1.5);
+    let shift = period / 4.0;
+    let t = t - 1.0;
+    let wave = ((t * d - shift) * std::f32::consts::TAU / period).sin();
+    if t < 0.0 {
+        -0.5 * (c * 2f32.powf(10.0 * t) * wave) + b
+    } else {
+        c * 2f32.powf(-10.0 * t) * wave * 0.5 + c + b
+    }
+}
+
+/// Bounce ease-out: drops onto the end value and bounces a few times
+pub fn ease_out_bounce(t: f32, b: f32, c: f32, d: f32) -> f32 {
+    if t >= d {
+        return b + c;
+    }
+    let t = t / d;
+    if t < 1.0 / 2.75 {
+        c * (7.5625 * t * t) + b
+    } else if t < 2.0 / 2.75 {
+        let t = t - 1.5 / 2.75;
+        c * (7.5625 * t * t + 0.75) + b
+    } else if t < 2.5 / 2.75 {
+        let t = t - 2.25 / 2.75;
+        c * (7.5625 * t * t + 0.9375) + b
+    } else {
+        let t = t - 2.625 / 2.75;
+        c * (7.5625 * t * t + 0.984375) + b
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use easer::functions::{Cubic, Easing};
+    use easer::functions::Cubic;
 
     #[test]
     fn test_tween_new() {
         let tween = Tween {
             duration: Duration::from_secs(1),
-            easing: Cubic::ease_in_out,
+            easing: Cubic::ease_in_out.into(),
         };
 
         assert_eq!(tween.duration, Duration::from_secs(1));
@@ -70,22 +475,208 @@ mod tests {
 
     #[test]
     fn test_tween_interpolation() {
-        let tween = Tween {
-            duration: Duration::from_secs(1),
-            easing: Linear::ease_in_out,
-        };
+        let tween = Tween::new(Duration::from_secs(1));
 
         // Test midpoint
         let progress = 0.5;
-        let result = (tween.easing)(progress, 0.0, 1.0, 1.0);
+        let result = tween.easing.ease(progress, 0.0, 1.0, 1.0);
         assert!((result - 0.5).abs() < f32::EPSILON);
 
         // Test start
-        let result = (tween.easing)(0.0, 0.0, 1.0, 1.0);
+        let result = tween.easing.ease(0.0, 0.0, 1.0, 1.0);
         assert!((result - 0.0).abs() < f32::EPSILON);
 
         // Test end
-        let result = (tween.easing)(1.0, 0.0, 1.0, 1.0);
+        let result = tween.easing.ease(1.0, 0.0, 1.0, 1.0);
         assert!((result - 1.0).abs() < f32::EPSILON);
     }
+
+    #[test]
+    fn test_easings_hit_endpoints_exactly() {
+        let easings = [
+            ease_in_quad,
+            ease_out_quad,
+            ease_in_out_cubic,
+            ease_out_back,
+            ease_in_out_elastic,
+            ease_out_bounce,
+        ];
+        for easing in easings {
+            assert_eq!(easing(0.0, 10.0, 90.0, 0.5), 10.0);
+            assert_eq!(easing(0.5, 10.0, 90.0, 0.5), 100.0);
+        }
+    }
+
+    #[test]
+    fn test_easing_shapes() {
+        assert_eq!(ease_in_quad(0.5, 0.0, 1.0, 1.0), 0.25);
+        assert_eq!(ease_out_quad(0.5, 0.0, 1.0, 1.0), 0.75);
+        assert_eq!(ease_in_out_cubic(0.5, 0.0, 1.0, 1.0), 0.5);
+        // Back overshoots before settling, bounce never leaves the range
+        assert!(ease_out_back(0.6, 0.0, 1.0, 1.0) > 1.0);
+        assert!((0..=100).all(|i| {
+            let value = ease_out_bounce(i as f32 / 100.0, 0.0, 1.0, 1.0);
+            (0.0..=1.0).contains(&value)
+        }));
+    }
+
+    #[test]
+    fn test_bezier_matches_css_curves() {
+        // cubic-bezier(0.42, 0, 0.58, 1) is CSS `ease-in-out`, symmetric around the midpoint
+        let ease_in_out = Tween::bezier(0.42, 0.0, 0.58, 1.0);
+        assert!((ease_in_out.easing.ease(0.5, 0.0, 1.0, 1.0) - 0.5).abs() < 1e-4);
+        assert!(ease_in_out.easing.ease(0.25, 0.0, 1.0, 1.0) < 0.25);
+
+        // Straight-line control points give linear timing
+        let linear = Tween::bezier(0.25, 0.25, 0.75, 0.75);
+        for i in 0..=10 {
+            let x = i as f32 / 10.0;
+            assert!((linear.easing.ease(x, 0.0, 1.0, 1.0) - x).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_bezier_endpoints_and_overshoot() {
+        let back = Tween::bezier(0.34, 1.56, 0.64, 1.0);
+        assert_eq!(back.easing.ease(0.0, 10.0, 90.0, 2.0), 10.0);
+        assert_eq!(back.easing.ease(2.0, 10.0, 90.0, 2.0), 100.0);
+        assert!(back.easing.ease(1.2, 0.0, 1.0, 2.0) > 1.0);
+    }
+
+    #[test]
+    fn test_sampled_easing_interpolates_between_samples() {
+        let tween = Tween::from_samples(vec![0.0, 0.8, 1.0]);
+        let ease = |t: f32| tween.easing.ease(t, 0.0, 1.0, 1.0);
+        assert_eq!(ease(0.0), 0.0);
+        assert!((ease(0.25) - 0.4).abs() < 1e-6);
+        assert!((ease(0.5) - 0.8).abs() < 1e-6);
+        assert!((ease(0.75) - 0.9).abs() < 1e-6);
+        assert_eq!(ease(1.0), 1.0);
+
+        // Progress outside the tween clamps to the first and last samples
+        assert_eq!(ease(-1.0), 0.0);
+        assert_eq!(ease(2.0), 1.0);
+    }
+
+    #[test]
+    fn test_sampled_easing_falls_back_to_linear() {
+        for samples in [vec![], vec![0.3]] {
+            let tween = Tween::from_samples(samples);
+            assert!((tween.easing.ease(0.25, 0.0, 1.0, 1.0) - 0.25).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_penner_easing_as_tween_easing() {
+        let tween = Tween::new(Duration::from_millis(500)).with_easing(ease_out_back);
+        assert_eq!(tween.easing.ease(1.0, 0.0, 1.0, 1.0), 1.0);
+    }
+
+    #[test]
+    fn test_named_easing_matches_its_function() {
+        let named = Easing::from(NamedEasing::EaseOutBounce);
+        assert_eq!(named.named(), Some(&NamedEasing::EaseOutBounce));
+        assert_eq!(
+            named.ease(0.4, 0.0, 1.0, 1.0),
+            ease_out_bounce(0.4, 0.0, 1.0, 1.0)
+        );
+        assert_eq!(named, NamedEasing::EaseOutBounce.into());
+        assert_ne!(named, NamedEasing::EaseOutBack.into());
+        assert_eq!(Easing::from(ease_out_bounce).named(), None);
+    }
+
+    #[test]
+    fn test_every_named_easing_runs_from_start_to_end() {
+        let named = [
+            NamedEasing::Linear,
+            NamedEasing::Ease,
+            NamedEasing::EaseIn,
+            NamedEasing::EaseOut,
+            NamedEasing::EaseInOut,
+            NamedEasing::EaseInQuad,
+            NamedEasing::EaseOutQuad,
+            NamedEasing::EaseInOutCubic,
+            NamedEasing::EaseOutBack,
+            NamedEasing::EaseInOutElastic,
+            NamedEasing::EaseOutBounce,
+            NamedEasing::CubicBezier(0.3, 0.0, 0.7, 1.0),
+            NamedEasing::Samples(vec![0.0, 0.2, 1.0]),
+            NamedEasing::Steps(4, JumpMode::End),
+        ];
+        for curve in named {
+            let easing = Easing::from(curve.clone());
+            assert!(
+                easing.ease(0.0, 0.0, 1.0, 1.0).abs() < 1e-4,
+                "{curve:?} start"
+            );
+            assert!(
+                (easing.ease(1.0, 0.0, 1.0, 1.0) - 1.0).abs() < 1e-4,
+                "{curve:?} end"
+            );
+        }
+    }
+
+    #[test]
+    fn test_named_easing_midpoints() {
+        let at = |curve: NamedEasing, t: f32| Easing::from(curve).ease(t, 0.0, 1.0, 1.0);
+        assert_eq!(at(NamedEasing::Linear, 0.3), 0.3);
+        // Symmetric curves cross the middle halfway through
+        assert!((at(NamedEasing::EaseInOut, 0.5) - 0.5).abs() < 1e-4);
+        assert!(at(NamedEasing::EaseIn, 0.5) < 0.5);
+        assert!(at(NamedEasing::EaseOut, 0.5) > 0.5);
+        assert!(at(NamedEasing::Ease, 0.5) > 0.5);
+        assert_eq!(at(NamedEasing::Samples(vec![0.0, 0.2, 1.0]), 0.25), 0.1);
+    }
+
+    #[test]
+    fn test_steps_jump_modes() {
+        let levels = |jump: JumpMode| {
+            let easing = Easing::from(NamedEasing::Steps(4, jump));
+            let mut levels: Vec<f32> = Vec::new();
+            for i in 0..100 {
+                let level = easing.ease(i as f32 / 100.0, 0.0, 1.0, 1.0);
+                if levels.last() != Some(&level) {
+                    levels.push(level);
+                }
+            }
+            (levels, easing.ease(1.0, 0.0, 1.0, 1.0))
+        };
+
+        // Four levels over the tween whichever way it jumps, always ending at the target
+        assert_eq!(levels(JumpMode::End), (vec![0.0, 0.25, 0.5, 0.75], 1.0));
+        assert_eq!(levels(JumpMode::Start), (vec![0.25, 0.5, 0.75, 1.0], 1.0));
+        assert_eq!(
+            levels(JumpMode::None),
+            (vec![0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0], 1.0)
+        );
+        assert_eq!(levels(JumpMode::Both), (vec![0.2, 0.4, 0.6, 0.8], 1.0));
+
+        let at = |steps: u32, jump: JumpMode, t: f32| {
+            Easing::from(NamedEasing::Steps(steps, jump)).ease(t, 0.0, 1.0, 1.0)
+        };
+        assert_eq!(at(0, JumpMode::End, 0.5), 0.0);
+        assert_eq!(at(1, JumpMode::None, 0.5), 1.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_tween_round_trips_through_serde() {
+        for tween in [
+            Tween::new(Duration::from_millis(250)).with_easing(NamedEasing::EaseInOutCubic),
+            Tween::bezier(0.25, 0.1, 0.25, 1.0),
+            Tween::from_samples(vec![0.0, 0.6, 1.0]),
+        ] {
+            let json = serde_json::to_string(&tween).expect("named easings serialize");
+            let restored: Tween = serde_json::from_str(&json).expect("and deserialize");
+            assert_eq!(restored, tween);
+            assert_eq!(
+                restored.easing.ease(0.3, 0.0, 1.0, 1.0),
+                tween.easing.ease(0.3, 0.0, 1.0, 1.0)
+            );
+        }
+
+        // A closure has no name to write out
+        let custom = Tween::default().with_easing(|t: f32, b: f32, c: f32, d: f32| b + c * t / d);
+        assert!(serde_json::to_string(&custom).is_err());
+    }
 }
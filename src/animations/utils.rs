@@ -4,9 +4,18 @@
 //! It provides support for both tweening and spring-based animations with configurable
 //! parameters.
 
-use std::sync::{Arc, Mutex};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    sync::{Arc, Mutex},
+};
 
-use crate::animations::{spring::Spring, tween::Tween};
+use crate::animations::{
+    colors::ColorSpace,
+    decay::Decay,
+    spring::{Spring, SpringCompletion, SpringSolver},
+    tween::{Easing, Tween},
+};
 use instant::Duration;
 
 /// A trait for types that can be animated
@@ -35,15 +44,118 @@ pub trait Animatable: Copy + 'static {
 
     /// Interpolates between self and target using t (0.0 to 1.0)
     fn interpolate(&self, target: &Self, t: f32) -> Self;
+
+    /// Interpolates in the given color space
+    ///
+    /// Only meaningful for color-like types; everything else ignores `space`
+    /// and falls back to [`Animatable::interpolate`].
+    fn interpolate_in(&self, target: &Self, t: f32, _space: ColorSpace) -> Self {
+        self.interpolate(target, t)
+    }
+
+    /// Maps every scalar component through `f(value, min, max)`, with the matching
+    /// components of `min` and `max`
+    ///
+    /// Used to keep values within [`Bounds`] and springs from passing their target with
+    /// [`AnimationConfig::with_clamp_to_target`]. Map each field the way `add` combines
+    /// them, e.g. `Self { x: f(self.x, min.x, max.x), y: f(self.y, min.y, max.y) }`.
+    fn map_within(&self, min: &Self, max: &Self, f: &dyn Fn(f32, f32, f32) -> f32) -> Self;
+
+    /// Rounds every scalar component to the nearest multiple of `grid`
+    ///
+    /// Used for [`AnimationConfig::with_snap_output`]. Like `map_within`, the default
+    /// returns the value unchanged, for types whose components don't share a unit.
+    fn snap(&self, _grid: f32) -> Self {
+        *self
+    }
+
+    /// Returns the value turned a quarter turn clockwise in screen coordinates (y down)
+    ///
+    /// Used to bend [`MotionPath::Arc`] paths. The default is zero, so types without a
+    /// 2D position always move in a straight line.
+    fn perpendicular(&self) -> Self {
+        Self::zero()
+    }
 }
 
-/// Defines the type of animation to be used
+/// Rounds `value` to the nearest multiple of `grid`
+pub(crate) fn snap_to_grid(value: f32, grid: f32) -> f32 {
+    (value / grid).round() * grid
+}
+
+/// Range an animated value is kept within, set on a motion with
+/// [`AnimationManager::set_bounds`](crate::AnimationManager::set_bounds)
+///
+/// Bounds shape the value that is shown, while springs keep moving past them underneath.
+/// A hard clamp cuts overshoot off at the edge: the value waits at the bound until the
+/// spring swings back. With rubber banding the overshoot still shows, but with
+/// resistance that grows the further it goes, like scroll views at their edges.
 #[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bounds<T> {
+    /// Lower bound of every component
+    pub min: T,
+    /// Upper bound of every component
+    pub max: T,
+    /// Elasticity past the bounds, `None` for a hard clamp
+    pub rubber_band: Option<f32>,
+}
+
+impl<T: Animatable> Bounds<T> {
+    /// Clamps every component of the value to `min..=max`
+    pub fn new(min: T, max: T) -> Self {
+        Self {
+            min,
+            max,
+            rubber_band: None,
+        }
+    }
+
+    /// Lets the value stretch past the bounds against increasing resistance
+    ///
+    /// `elasticity` sets how far it gives: iOS scroll views use 0.55, lower values are stiffer.
+    pub fn with_rubber_band(mut self, elasticity: f32) -> Self {
+        self.rubber_band = Some(elasticity.max(0.0));
+        self
+    }
+
+    /// Returns `value` clamped, or rubber banded, into the bounds
+    pub fn apply(&self, value: T) -> T {
+        value.map_within(&self.min, &self.max, &|v, min, max| {
+            self.rubber_band.map_or_else(
+                || v.max(min).min(max),
+                |elasticity| rubber_band(v, min, max, elasticity),
+            )
+        })
+    }
+}
+
+/// Pulls `value` back towards `min..=max` with the rubber band curve used by iOS scroll
+/// views, which approaches one range length past the edge but never reaches it
+fn rubber_band(value: f32, min: f32, max: f32, elasticity: f32) -> f32 {
+    let range = max - min;
+    if range <= 0.0 {
+        return value.max(min).min(max);
+    }
+    let resist = |overshoot: f32| (1.0 - 1.0 / (overshoot * elasticity / range + 1.0)) * range;
+    if value > max {
+        max + resist(value - max)
+    } else if value < min {
+        min - resist(min - value)
+    } else {
+        value
+    }
+}
+
+/// Defines the type of animation to be used
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AnimationMode {
     /// Tween animation with duration and easing
     Tween(Tween),
     /// Physics-based spring animation
     Spring(Spring),
+    /// Momentum that slows down under friction, resting wherever it runs out
+    Decay(Decay),
 }
 
 impl Default for AnimationMode {
@@ -54,17 +166,58 @@ impl Default for AnimationMode {
 
 /// Defines how the animation should loop
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LoopMode {
     /// Play animation once
     None,
     /// Loop animation indefinitely
     Infinite,
     /// Loop animation a specific number of times
-    Times(u8),
+    Times(u32),
     /// Loop animation back and forth indefinitely
     Alternate,
     /// Loop animation back and forth a specific number of times
-    AlternateTimes(u8),
+    AlternateTimes(u32),
+    /// Play forward then backward indefinitely, swapping `initial` and `target` on each leg
+    ///
+    /// Springs turn as they reach the target, keeping their speed, rather than settling
+    /// there first.
+    PingPong,
+    /// Play a specific number of forward-and-back cycles, ending at the starting value
+    PingPongTimes(u32),
+}
+
+/// Velocity a spring starts each loop with
+///
+/// Springs don't end after a fixed time the way tweens do: a loop finishes once the
+/// spring has settled on its target, so it has no momentum left to carry into the next
+/// loop. Instead, the next loop can be given a push from `Spring::velocity`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LoopVelocity {
+    /// Start every loop from rest
+    #[default]
+    Reset,
+    /// Start every loop moving towards the target at the spring's `velocity`
+    Seeded,
+}
+
+/// Route a tween takes from its start to its target
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MotionPath {
+    /// Straight interpolation between the two
+    #[default]
+    Straight,
+    /// A quadratic arc, bending to the right of the direction of travel in screen
+    /// coordinates (y down) for positive curvature and to the left for negative
+    ///
+    /// The arc's control point sits off the midpoint of the straight line, at right angles
+    /// to it, `curvature` times the distance travelled away. The path passes halfway to it,
+    /// so a curvature of 0.5 bows out by a quarter of the distance. Only the x/y position
+    /// of `Vector2D`, `Transform` and tuples containing them bends; the return leg of an
+    /// alternating loop bends to the other side, tracing a loop.
+    Arc(f32),
 }
 
 impl Default for LoopMode {
@@ -74,17 +227,111 @@ impl Default for LoopMode {
 }
 
 pub type OnComplete = Arc<Mutex<dyn FnMut() + Send + 'static>>;
+/// Per-frame callback of a motion of `T`, see [`Motion::on_update`](crate::Motion::on_update)
+pub type OnUpdate<T> = Rc<RefCell<dyn FnMut(T)>>;
+/// Completion callback waiting on the running animation of a motion of `T`, see
+/// [`Motion::on_complete_with`](crate::Motion::on_complete_with)
+pub type OnCompleteWith<T> = Rc<Cell<Option<Box<dyn FnOnce(T)>>>>;
+/// Callback receiving the index of each new iteration of a looping animation, stored in [`AnimationConfig`]
+pub type OnLoop = Mutex<dyn FnMut(u32) + Send + 'static>;
 /// Configuration for an animation
-#[derive(Clone, Default)]
+///
+/// Start from [`AnimationConfig::new`] (or `default()`, a linear tween) and chain the
+/// `with_*` builders; every option has one and each returns `Self`:
+///
+/// - timing: [`with_mode`](Self::with_mode), [`with_delay`](Self::with_delay),
+///   [`with_loop`](Self::with_loop), [`with_repeat_delay`](Self::with_repeat_delay),
+///   [`with_speed`](Self::with_speed),
+///   [`with_path`](Self::with_path)
+/// - callbacks: [`with_on_complete`](Self::with_on_complete),
+///   [`with_on_loop`](Self::with_on_loop); callbacks that receive the value are registered
+///   on the motion itself, typed to it, with
+///   [`AnimationManager::on_update`](crate::AnimationManager::on_update) and
+///   [`AnimationManager::on_complete_with`](crate::AnimationManager::on_complete_with)
+/// - springs: [`with_spring_solver`](Self::with_spring_solver),
+///   [`with_spring_completion`](Self::with_spring_completion),
+///   [`with_loop_velocity`](Self::with_loop_velocity),
+///   [`with_preserve_velocity_on_retarget`](Self::with_preserve_velocity_on_retarget),
+///   [`with_lead_time`](Self::with_lead_time),
+///   [`with_clamp_to_target`](Self::with_clamp_to_target)
+/// - values: [`with_color_space`](Self::with_color_space),
+///   [`with_completion_epsilon`](Self::with_completion_epsilon),
+///   [`with_snap_output`](Self::with_snap_output),
+///   [`with_output_easing`](Self::with_output_easing)
+/// - accessibility: [`with_respect_reduced_motion`](Self::with_respect_reduced_motion)
+///
+/// With the `serde` feature a config can be loaded from a preset file. Callbacks are left
+/// out, missing fields take their defaults, and tweens need a
+/// [`NamedEasing`](crate::prelude::NamedEasing) to be written out.
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::prelude::*;
+/// let config = AnimationConfig::default()
+///     .with_mode(AnimationMode::Spring(Spring::gentle()))
+///     .with_delay(Duration::from_millis(100))
+///     .with_loop(LoopMode::Times(2))
+///     .with_speed(1.5)
+///     .with_on_complete(|| println!("done"));
+/// ```
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct AnimationConfig {
-    /// The type of animation (Tween or Spring)
+    /// The type of animation (tween, spring or decay)
     pub mode: AnimationMode,
     /// How the animation should loop
     pub loop_mode: Option<LoopMode>,
     /// Delay before animation starts
     pub delay: Duration,
+    /// Pause between the iterations of a looping animation (default: none)
+    pub repeat_delay: Duration,
     /// Callback when animation completes
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub on_complete: Option<Arc<Mutex<dyn FnMut() + Send>>>,
+    /// Callback with the new loop index each time a looping animation starts another iteration
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub on_loop: Option<Arc<OnLoop>>,
+    /// Color space used when tweening colors
+    pub color_space: ColorSpace,
+    /// Playback speed multiplier (default: 1.0)
+    /// Values below 1.0 slow the animation down, values above 1.0 speed it up
+    pub speed: f32,
+    /// Jump straight to the target when the user asks for reduced motion (default: false)
+    /// See [`prefers_reduced_motion`](crate::animations::platform::prefers_reduced_motion)
+    pub respect_reduced_motion: bool,
+    /// Keep the current velocity when a spring is retargeted mid-flight (default: true)
+    /// Has no effect on tweens
+    pub preserve_velocity_on_retarget: bool,
+    /// How spring animations are stepped (default: [`SpringSolver::Integrated`])
+    pub spring_solver: SpringSolver,
+    /// Velocity looping springs start each loop with (default: [`LoopVelocity::Reset`])
+    pub loop_velocity: LoopVelocity,
+    /// How far ahead along a moving target's path a spring aims (default: zero, no lead)
+    pub lead_time: Duration,
+    /// Stop springs at their target instead of overshooting it (default: false)
+    pub clamp_to_target: bool,
+    /// Route tweens take to their target (default: [`MotionPath::Straight`])
+    pub path: MotionPath,
+    /// Grid the observed value is rounded to, leaving the animation itself continuous
+    pub snap_output: Option<f32>,
+    /// Curve the observed progress towards the target is passed through, leaving the
+    /// animation itself unchanged
+    pub output_easing: Option<Easing>,
+    /// When a spring counts as finished (default: [`SpringCompletion::Thresholds`])
+    pub spring_completion: SpringCompletion,
+    /// Distance and speed below which a spring or decay counts as settled
+    /// Defaults to `0.001` for springs and `T::epsilon()` for decays
+    pub completion_epsilon: Option<f32>,
+}
+
+impl Default for AnimationConfig {
+    fn default() -> Self {
+        Self::new(AnimationMode::default())
+    }
 }
 
 impl AnimationConfig {
@@ -94,22 +341,175 @@ impl AnimationConfig {
             mode,
             loop_mode: None,
             delay: Duration::default(),
+            repeat_delay: Duration::default(),
             on_complete: None,
+            on_loop: None,
+            color_space: ColorSpace::default(),
+            speed: 1.0,
+            respect_reduced_motion: false,
+            preserve_velocity_on_retarget: true,
+            spring_solver: SpringSolver::default(),
+            loop_velocity: LoopVelocity::default(),
+            lead_time: Duration::ZERO,
+            clamp_to_target: false,
+            completion_epsilon: None,
+            spring_completion: SpringCompletion::default(),
+            snap_output: None,
+            output_easing: None,
+            path: MotionPath::default(),
         }
     }
 
+    /// Sets the type of animation, replacing the one given to `new`
+    pub fn with_mode(mut self, mode: AnimationMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
     /// Sets the loop mode for the animation
     pub fn with_loop(mut self, loop_mode: LoopMode) -> Self {
         self.loop_mode = Some(loop_mode);
         self
     }
 
+    /// Sets the velocity looping springs start each loop with
+    pub fn with_loop_velocity(mut self, loop_velocity: LoopVelocity) -> Self {
+        self.loop_velocity = loop_velocity;
+        self
+    }
+
     /// Sets a delay before the animation starts
     pub fn with_delay(mut self, delay: Duration) -> Self {
         self.delay = delay;
         self
     }
 
+    /// Sets a pause between the iterations of a looping animation
+    ///
+    /// After each iteration the value holds where the next one starts for `repeat_delay`,
+    /// e.g. for a notification dot that pulses every few seconds. The first iteration waits
+    /// for [`with_delay`](Self::with_delay) as usual.
+    pub fn with_repeat_delay(mut self, repeat_delay: Duration) -> Self {
+        self.repeat_delay = repeat_delay;
+        self
+    }
+
+    /// Sets the color space used when tweening colors
+    pub fn with_color_space(mut self, color_space: ColorSpace) -> Self {
+        self.color_space = color_space;
+        self
+    }
+
+    /// Sets the playback speed multiplier, e.g. 0.5 for half speed or 2.0 for double speed
+    ///
+    /// The app-wide [`MotionConfig::global_speed`](crate::MotionConfig::global_speed) scales it
+    /// further.
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed.max(0.0);
+        self
+    }
+
+    /// Makes the animation finish instantly on its first frame when reduced motion is preferred
+    pub fn with_respect_reduced_motion(mut self, respect: bool) -> Self {
+        self.respect_reduced_motion = respect;
+        self
+    }
+
+    /// Sets whether a spring started while another animation is running carries on with its
+    /// velocity, instead of restarting from rest
+    pub fn with_preserve_velocity_on_retarget(mut self, preserve: bool) -> Self {
+        self.preserve_velocity_on_retarget = preserve;
+        self
+    }
+
+    /// Makes a spring following a moving target aim `lead_time` ahead of it
+    ///
+    /// A spring chasing the pointer always trails behind it. With a lead time, each
+    /// `animate_to` or `retarget` estimates how fast the target is moving from the previous
+    /// call and aims where it will be `lead_time` later, closing the gap. The estimate needs
+    /// the target to be updated often, such as on every pointer move; once it stops being
+    /// moved for 100ms, or is asked for twice in the same place, the spring heads back to the
+    /// target itself. Only springs lead, and tweens or decays ignore it.
+    pub fn with_lead_time(mut self, lead_time: Duration) -> Self {
+        self.lead_time = lead_time;
+        self
+    }
+
+    /// Makes springs approach their target without ever passing it
+    ///
+    /// Each component is held at its target once it gets there and loses its velocity,
+    /// so the spring keeps its organic acceleration without the overshoot, for values like
+    /// progress bars that must never exceed where they're heading. Components are clamped
+    /// through [`Animatable::map_within`].
+    pub fn with_clamp_to_target(mut self, clamp: bool) -> Self {
+        self.clamp_to_target = clamp;
+        self
+    }
+
+    /// Sets how spring animations are stepped each frame
+    pub fn with_spring_solver(mut self, solver: SpringSolver) -> Self {
+        self.spring_solver = solver;
+        self
+    }
+
+    /// Sets how close to rest a spring or decay has to get before it finishes
+    ///
+    /// Both the remaining distance and the velocity are measured with
+    /// [`Animatable::magnitude`], so for multi-component values the threshold applies to
+    /// their combined length rather than to each component. Raise it for large-range values
+    /// like pixel positions, which otherwise spend a long time settling the last fraction of
+    /// a pixel; lower it for small ranges like opacity, keeping it above the `f32` precision
+    /// of the values or the animation may never settle.
+    pub fn with_completion_epsilon(mut self, epsilon: f32) -> Self {
+        self.completion_epsilon = Some(epsilon.max(0.0));
+        self
+    }
+
+    /// Sets how a spring decides it has settled
+    pub fn with_spring_completion(mut self, completion: SpringCompletion) -> Self {
+        self.spring_completion = completion;
+        self
+    }
+
+    /// Sets the route a tween takes to its target, e.g. [`MotionPath::Arc`] for a curved path
+    ///
+    /// Springs and decays always head straight for their target.
+    pub fn with_path(mut self, path: MotionPath) -> Self {
+        self.path = path;
+        self
+    }
+
+    /// Rounds the value read from the animation to the nearest multiple of `grid`
+    ///
+    /// Only what `get_value` and `peek_value` return is snapped, so the physics underneath
+    /// stays smooth, e.g. `with_snap_output(1.0)` keeps pixel positions from landing on
+    /// subpixels and blurring text. Applies to every component of numbers, vectors, arrays and
+    /// tuples; types like `Transform` and `Color`, whose components differ in unit, ignore it.
+    pub fn with_snap_output(mut self, grid: f32) -> Self {
+        self.snap_output = Some(grid).filter(|grid| *grid > 0.0);
+        self
+    }
+
+    /// Reshapes the value read from the animation by passing its progress through `easing`
+    ///
+    /// Progress runs from 0.0 at the start to 1.0 at the target, and the value read is put
+    /// where the eased progress would be, e.g. squaring the progress exaggerates a spring's
+    /// overshoot. Unlike a tween's easing this maps the observed value only:
+    /// velocity, completion and everything else run on the true physics, so a spring keeps
+    /// its timing. `easing` should map 1.0 to 1.0, and is also called with progress below
+    /// 0.0 or above 1.0 while a spring overshoots, so curves that extrapolate smoothly work
+    /// best. Applies to every mode but is meant for springs. Like
+    /// [`with_snap_output`](Self::with_snap_output) it only affects `get_value` and
+    /// `peek_value`, and runs before the snapping.
+    ///
+    /// The reshaping is relative to the start and target of the running animation, so
+    /// interrupting it moves the value underneath to where it was shown: the next
+    /// animation, a retargeted spring and a stopped value all carry on from there.
+    pub fn with_output_easing(mut self, easing: impl Into<Easing>) -> Self {
+        self.output_easing = Some(easing.into());
+        self
+    }
+
     /// Sets a callback to be called when animation completes
     pub fn with_on_complete<F>(mut self, f: F) -> Self
     where
@@ -119,6 +519,19 @@ impl AnimationConfig {
         self
     }
 
+    /// Sets a callback to be called each time a looping animation restarts
+    ///
+    /// Receives the index of the iteration that's starting, so `1` at the first restart.
+    /// Alternating and ping-pong loops count each leg. Unlike `on_complete`, which only
+    /// fires once the loops are over, this never fires for the final iteration.
+    pub fn with_on_loop<F>(mut self, f: F) -> Self
+    where
+        F: FnMut(u32) + Send + 'static,
+    {
+        self.on_loop = Some(Arc::new(Mutex::new(f)));
+        self
+    }
+
     /// Gets the total duration of the animation
     pub fn get_duration(&self) -> Duration {
         match &self.mode {
@@ -126,13 +539,22 @@ impl AnimationConfig {
                 // Springs don't have a fixed duration, estimate based on typical settling time
                 Duration::from_secs_f32(1.0) // You might want to adjust this based on spring parameters
             }
+            AnimationMode::Decay(decay) => decay.estimated_settling_time(),
             AnimationMode::Tween(tween) => {
-                let base_duration = tween.duration;
+                let base_duration = if self.speed > 0.0 {
+                    tween.duration.div_f32(self.speed)
+                } else {
+                    tween.duration
+                };
                 match self.loop_mode {
                     Some(LoopMode::Infinite) => Duration::from_secs(f32::INFINITY as u64),
-                    Some(LoopMode::Times(count)) => base_duration * count.into(),
+                    Some(LoopMode::Times(count)) => base_duration.saturating_mul(count),
                     Some(LoopMode::Alternate) => Duration::from_secs(f32::INFINITY as u64),
-                    Some(LoopMode::AlternateTimes(count)) => base_duration * (count * 2).into(),
+                    Some(LoopMode::AlternateTimes(count))
+                    | Some(LoopMode::PingPongTimes(count)) => {
+                        base_duration.saturating_mul(count.saturating_mul(2))
+                    }
+                    Some(LoopMode::PingPong) => Duration::from_secs(f32::INFINITY as u64),
                     Some(LoopMode::None) | None => base_duration,
                 }
             }
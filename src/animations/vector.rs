@@ -0,0 +1,184 @@
+//! Vector module for 2D positions
+//!
+//! Provides a Vector2D type that animates both axes together,
+//! so x and y can never drift apart when an animation is interrupted.
+//! Fixed-size `[f32; N]` arrays animate the same way, for sets of values
+//! such as bar heights that must stay in sync.
+
+use crate::animations::utils::{Animatable, snap_to_grid};
+
+/// Represents a 2D vector with x and y components
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::prelude::Vector2D;
+/// let position = Vector2D::new(100.0, 50.0);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vector2D {
+    /// X component
+    pub x: f32,
+    /// Y component
+    pub y: f32,
+}
+
+impl Vector2D {
+    /// Creates a new vector from its components
+    pub fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+}
+
+/// Implementation of Animatable for Vector2D
+/// Treats both axes as a single value for physics and completion checks
+impl Animatable for Vector2D {
+    /// Creates a zero vector
+    fn zero() -> Self {
+        Vector2D::new(0.0, 0.0)
+    }
+
+    /// Minimum meaningful difference between vectors
+    fn epsilon() -> f32 {
+        0.001
+    }
+
+    /// Euclidean length of the vector
+    fn magnitude(&self) -> f32 {
+        (self.x * self.x + self.y * self.y).sqrt()
+    }
+
+    /// Scales both components by a factor
+    fn scale(&self, factor: f32) -> Self {
+        Vector2D::new(self.x * factor, self.y * factor)
+    }
+
+    /// Adds two vectors component-wise
+    fn add(&self, other: &Self) -> Self {
+        Vector2D::new(self.x + other.x, self.y + other.y)
+    }
+
+    /// Subtracts two vectors component-wise
+    fn sub(&self, other: &Self) -> Self {
+        Vector2D::new(self.x - other.x, self.y - other.y)
+    }
+
+    /// Linearly interpolates between two vectors
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        Vector2D::new(
+            self.x + (target.x - self.x) * t,
+            self.y + (target.y - self.y) * t,
+        )
+    }
+
+    /// Bounds each axis separately, so a box can be dragged freely along one edge
+    fn map_within(&self, min: &Self, max: &Self, f: &dyn Fn(f32, f32, f32) -> f32) -> Self {
+        Vector2D::new(f(self.x, min.x, max.x), f(self.y, min.y, max.y))
+    }
+
+    fn snap(&self, grid: f32) -> Self {
+        Vector2D::new(snap_to_grid(self.x, grid), snap_to_grid(self.y, grid))
+    }
+
+    fn perpendicular(&self) -> Self {
+        Vector2D::new(-self.y, self.x)
+    }
+}
+
+/// Implementation of Animatable for fixed-size float arrays
+/// Treats the array as one N-dimensional vector, so every element settles together
+impl<const N: usize> Animatable for [f32; N] {
+    /// Creates an all-zero array
+    fn zero() -> Self {
+        [0.0; N]
+    }
+
+    /// Same threshold as a single float
+    fn epsilon() -> f32 {
+        f32::epsilon()
+    }
+
+    /// Euclidean norm across all elements
+    fn magnitude(&self) -> f32 {
+        self.iter().map(|v| v * v).sum::<f32>().sqrt()
+    }
+
+    /// Scales every element by a factor
+    fn scale(&self, factor: f32) -> Self {
+        self.map(|v| v * factor)
+    }
+
+    /// Adds two arrays element-wise
+    fn add(&self, other: &Self) -> Self {
+        std::array::from_fn(|i| self[i] + other[i])
+    }
+
+    /// Subtracts two arrays element-wise
+    fn sub(&self, other: &Self) -> Self {
+        std::array::from_fn(|i| self[i] - other[i])
+    }
+
+    /// Linearly interpolates each element
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        std::array::from_fn(|i| self[i] + (target[i] - self[i]) * t)
+    }
+
+    /// Bounds each element by the matching elements of `min` and `max`
+    fn map_within(&self, min: &Self, max: &Self, f: &dyn Fn(f32, f32, f32) -> f32) -> Self {
+        std::array::from_fn(|i| f(self[i], min[i], max[i]))
+    }
+
+    fn snap(&self, grid: f32) -> Self {
+        self.map(|value| snap_to_grid(value, grid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector_new() {
+        let vector = Vector2D::new(3.0, 4.0);
+        assert_eq!(vector.x, 3.0);
+        assert_eq!(vector.y, 4.0);
+    }
+
+    #[test]
+    fn test_vector_magnitude() {
+        let vector = Vector2D::new(3.0, 4.0);
+        assert!((vector.magnitude() - 5.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_vector_arithmetic() {
+        let a = Vector2D::new(1.0, 2.0);
+        let b = Vector2D::new(3.0, 5.0);
+        assert_eq!(a.add(&b), Vector2D::new(4.0, 7.0));
+        assert_eq!(b.sub(&a), Vector2D::new(2.0, 3.0));
+        assert_eq!(a.scale(2.0), Vector2D::new(2.0, 4.0));
+        assert_eq!(Vector2D::zero(), Vector2D::default());
+    }
+
+    #[test]
+    fn test_vector_lerp() {
+        let start = Vector2D::new(0.0, 0.0);
+        let end = Vector2D::new(100.0, -50.0);
+        let mid = start.interpolate(&end, 0.5);
+
+        assert_eq!(mid.x, 50.0);
+        assert_eq!(mid.y, -25.0);
+    }
+
+    #[test]
+    fn test_array_arithmetic() {
+        let a = [1.0, 2.0, 2.0];
+        let b = [3.0, 5.0, -1.0];
+        assert_eq!(a.add(&b), [4.0, 7.0, 1.0]);
+        assert_eq!(b.sub(&a), [2.0, 3.0, -3.0]);
+        assert_eq!(a.scale(2.0), [2.0, 4.0, 4.0]);
+        assert_eq!(a.interpolate(&b, 0.5), [2.0, 3.5, 0.5]);
+        assert!((a.magnitude() - 3.0).abs() < f32::EPSILON);
+        assert_eq!(<[f32; 3]>::zero(), [0.0; 3]);
+    }
+}
@@ -0,0 +1,134 @@
+//! Declarative components built on the motion hooks
+//!
+//! These wrap [`use_motion`] for the common case of animating one value towards a target
+//! that comes from props. The hooks remain the way to go for anything more involved.
+
+use dioxus::prelude::*;
+
+use crate::{
+    AnimationManager,
+    animations::utils::{Animatable, AnimationConfig, AnimationMode},
+    use_motion,
+};
+
+/// Animates a value towards `target` and renders it through `render`
+///
+/// The value starts at `initial` and animates to `target` on mount. When the parent
+/// re-renders with a different `target` or `mode`, the animation is retargeted from wherever
+/// it currently is, so springs keep their momentum. `render` is called with the current
+/// value on every frame and its result is what the component shows.
+///
+/// `mode` is compared with the last render's to spot a change. Springs, and tweens eased
+/// with a [`NamedEasing`](crate::prelude::NamedEasing) or a bezier, compare by value, but
+/// an easing function gets a new curve each time it is converted, so a tween built with
+/// one in render looks new on every render and restarts the animation. Build such a mode
+/// once, e.g. with `use_hook`, and pass a clone.
+///
+/// # Example
+///
+/// ```no_run
+/// use dioxus::prelude::*;
+/// use dioxus_motion::prelude::*;
+///
+/// fn app() -> Element {
+///     let mut open = use_signal(|| false);
+///
+///     rsx! {
+///         button { onclick: move |_| open.toggle(), "Toggle" }
+///         AnimatedValue {
+///             initial: 0.0f32,
+///             target: if open() { 1.0 } else { 0.0 },
+///             mode: AnimationMode::Spring(Spring::default()),
+///             render: move |opacity: f32| rsx! {
+///                 div { style: "opacity: {opacity}", "Hello" }
+///             },
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn AnimatedValue<T: Animatable + PartialEq>(
+    initial: T,
+    target: T,
+    mode: AnimationMode,
+    render: Callback<T, Element>,
+) -> Element {
+    let mut value = use_motion(initial);
+
+    // Props aren't reactive on their own, so track them explicitly
+    use_effect(use_reactive((&target, &mode), move |(target, mode)| {
+        value.animate_to(target, AnimationConfig::new(mode));
+    }));
+
+    render.call(value.get_value())
+}
+
+/// Keeps its content mounted while it animates out, like `AnimatePresence` in JS motion
+/// libraries
+///
+/// Rendering something conditionally removes it at once, leaving no time for an exit
+/// animation. Instead, render `AnimatePresence` unconditionally and pass the condition as
+/// `present`. `render` is called with a progress value that animates from 0.0 to 1.0 with
+/// `enter` when `present` becomes true, and back to 0.0 with `exit` when it becomes false;
+/// map it to opacity, a transform or anything else. The content stays mounted until the
+/// exit animation has finished and is unmounted after that. If `present` flips back while
+/// the content is leaving, it enters again from wherever it got to.
+///
+/// Content that is present on the first render plays its enter animation on mount.
+///
+/// `enter` and `exit` are compared between renders like the `mode` of [`AnimatedValue`],
+/// so tweens eased by a function should be built once rather than in render.
+///
+/// # Example
+///
+/// ```no_run
+/// use dioxus::prelude::*;
+/// use dioxus_motion::prelude::*;
+///
+/// fn app() -> Element {
+///     let mut open = use_signal(|| false);
+///
+///     rsx! {
+///         button { onclick: move |_| open.toggle(), "Toggle" }
+///         AnimatePresence {
+///             present: open(),
+///             enter: AnimationMode::Spring(Spring::default()),
+///             exit: AnimationMode::Tween(Tween::new(Duration::from_millis(150))),
+///             render: move |progress: f32| rsx! {
+///                 div {
+///                     style: "opacity: {progress}; transform: scale({0.9 + 0.1 * progress})",
+///                     "Hello"
+///                 }
+///             },
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn AnimatePresence(
+    present: bool,
+    #[props(default)] enter: AnimationMode,
+    #[props(default)] exit: AnimationMode,
+    render: Callback<f32, Element>,
+) -> Element {
+    let mut progress = use_motion(0.0f32);
+
+    use_effect(use_reactive(
+        (&present, &enter, &exit),
+        move |(present, enter, exit)| {
+            let (target, mode) = if present { (1.0, enter) } else { (0.0, exit) };
+            // Content that mounts hidden is already where the exit would leave it
+            if !progress.is_running() && progress.peek_value() == target {
+                return;
+            }
+            progress.animate_to(target, AnimationConfig::new(mode));
+        },
+    ));
+
+    // Gone once the exit has played out; while it runs the content is still rendered
+    let shown = progress.get_value();
+    if !present && !progress.is_running() && shown <= 0.0 {
+        return rsx! {};
+    }
+    render.call(shown)
+}
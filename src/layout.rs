@@ -0,0 +1,149 @@
+//! Layout animations for elements that move when the page around them changes
+//!
+//! Follows the FLIP technique: the element's position is measured after every render
+//! (First and Last), an offset that puts it back where it was is applied (Invert), and that
+//! offset animates to zero (Play). The element is rendered at its new place in the layout
+//! the whole time, it just looks like it travelled there.
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::animations::utils::{Animatable, AnimationConfig};
+use crate::prelude::{Transform, Vector2D};
+use crate::{AnimationManager, Motion, ticker};
+
+/// Movement smaller than this, in pixels, is left alone rather than animated
+const LAYOUT_EPSILON: f32 = 0.5;
+
+/// The element a [`LayoutMotion`] measures, and where it last was in the layout
+struct LayoutState {
+    element: Option<Rc<MountedData>>,
+    layout: Option<Vector2D>, // Position in the layout, without the animated offset
+    config: AnimationConfig,
+}
+
+/// An element's offset from its place in the layout, animated whenever that place moves
+///
+/// Hand it the element with [`LayoutMotion::onmounted`] and apply
+/// [`LayoutMotion::get_value`] as the element's transform. The position is measured after
+/// every render of the component that called [`use_layout_motion`], so that component has to
+/// re-render when the element may have moved. In a keyed list, passing each item its index
+/// as a prop does that. Only the position is animated, not the size.
+///
+/// Measuring uses `getBoundingClientRect` and needs the `web` feature. On other platforms
+/// the element jumps to its new place as if it had no layout animation.
+#[derive(Clone, Copy)]
+pub struct LayoutMotion {
+    offset: Signal<Motion<Vector2D>>,
+    state: Signal<LayoutState>,
+}
+
+impl LayoutMotion {
+    /// Takes the element to measure, for the element's `onmounted` handler
+    pub fn onmounted(&mut self, event: MountedEvent) {
+        self.state.write().element = Some(event.data());
+        // Records where it starts, so the first move after mounting animates too
+        self.measure();
+    }
+
+    /// Offset to apply to the element, as a translation
+    pub fn get_value(&self) -> Transform {
+        let offset = self.offset.get_value();
+        Transform::translate(offset.x, offset.y)
+    }
+
+    /// True while the element is travelling to its new place
+    pub fn is_running(&self) -> bool {
+        self.offset.is_running()
+    }
+
+    /// Measures the element and, if it moved in the layout, animates it over from where it was
+    fn measure(&mut self) {
+        let Some(position) = self.state.peek().element.as_deref().and_then(page_position) else {
+            return;
+        };
+
+        // The measured box includes the offset the element is drawn with
+        let offset = self.offset.peek_value();
+        let layout = position.sub(&offset);
+        let (previous, config) = {
+            let mut state = self.state.write();
+            (state.layout.replace(layout), state.config.clone())
+        };
+        let Some(previous) = previous else {
+            return;
+        };
+
+        let moved = previous.sub(&layout);
+        if moved.magnitude() >= LAYOUT_EPSILON {
+            ticker::drive(self.offset, |motion| {
+                motion.shift_by(moved);
+                motion.animate_to(Vector2D::zero(), config);
+            });
+        }
+    }
+}
+
+/// Position of the element's top left corner relative to the page
+#[cfg(feature = "web")]
+fn page_position(element: &MountedData) -> Option<Vector2D> {
+    let rect = element
+        .downcast::<web_sys::Element>()?
+        .get_bounding_client_rect();
+    // Relative to the page rather than the viewport, so scrolling doesn't count as moving
+    let window = web_sys::window()?;
+    let scroll_x = window.scroll_x().unwrap_or_default();
+    let scroll_y = window.scroll_y().unwrap_or_default();
+    Some(Vector2D::new(
+        (rect.left() + scroll_x) as f32,
+        (rect.top() + scroll_y) as f32,
+    ))
+}
+
+/// Position of the element's top left corner relative to the page
+#[cfg(not(feature = "web"))]
+fn page_position(_element: &MountedData) -> Option<Vector2D> {
+    None
+}
+
+/// Animates an element to its new place whenever the layout moves it, such as when a list
+/// is reordered
+///
+/// See [`LayoutMotion`] for when the element is measured. `config` is used for every move.
+///
+/// # Example
+///
+/// ```no_run
+/// use dioxus::prelude::*;
+/// use dioxus_motion::prelude::*;
+///
+/// #[component]
+/// fn Item(label: String, index: usize) -> Element {
+///     let mut layout =
+///         use_layout_motion(AnimationConfig::new(AnimationMode::Spring(Spring::default())));
+///
+///     rsx! {
+///         li {
+///             onmounted: move |event| layout.onmounted(event),
+///             style: "transform: {layout.get_value().to_css_transform()}",
+///             "{label}"
+///         }
+///     }
+/// }
+/// ```
+pub fn use_layout_motion(config: AnimationConfig) -> LayoutMotion {
+    let mut layout = use_hook(|| LayoutMotion {
+        offset: Signal::new(Motion::new(Vector2D::zero())),
+        state: Signal::new(LayoutState {
+            element: None,
+            layout: None,
+            config,
+        }),
+    });
+
+    // Effects run once the DOM is up to date, so this sees the layout of this render
+    queue_effect(move || layout.measure());
+
+    layout
+}
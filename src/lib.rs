@@ -8,6 +8,9 @@
 //! - Tween animations with custom easing
 //! - Color interpolation
 //! - Transform animations
+//! - 2D vector animations
+//! - Quaternion-based 3D rotation animations
+//! - Fixed-size `[f32; N]` array animations
 //! - Configurable animation loops
 //! - Animation sequences
 //!
@@ -32,58 +35,124 @@
 #![deny(clippy::option_if_let_else)] // Prefer map/and_then
 #![deny(clippy::option_if_let_else)] // Prefer map/and_then
 
-use std::{cell::RefCell, sync::Arc};
+use std::{
+    cell::{Cell, RefCell},
+    future::Future,
+    marker::PhantomData,
+    rc::Rc,
+    sync::Arc,
+};
 
-use animations::utils::{Animatable, AnimationMode};
+use animations::utils::{Animatable, AnimationMode, Bounds, MotionPath, OnCompleteWith, OnUpdate};
 use dioxus::prelude::*;
+use futures_channel::mpsc::{UnboundedReceiver, UnboundedSender};
 pub use instant::Duration;
+use instant::Instant;
 
 pub mod animations;
+pub mod components;
+pub mod layout;
+mod ticker;
 pub mod transitions;
 
 #[cfg(feature = "transitions")]
 pub use dioxus_motion_transitions_macro;
 
-pub use animations::platform::{MotionTime, TimeProvider};
-use animations::spring::{Spring, SpringState};
-use prelude::{AnimationConfig, LoopMode, Transform, Tween};
+use animations::decay::Decay;
+pub use animations::platform::{
+    MotionConfig, MotionTime, TestTime, TimeProvider, motion_config, prefers_reduced_motion,
+    set_motion_config, set_reduced_motion,
+};
+use animations::spring::{Spring, SpringCompletion, SpringSolver, SpringState};
+use animations::tween::Easing;
+use prelude::{AnimationConfig, LoopMode, LoopVelocity, NamedEasing, Transform, Tween, Vector2D};
 use smallvec::SmallVec;
 
 // Re-exports
 pub mod prelude {
-    pub use crate::animations::utils::{AnimationConfig, AnimationMode, LoopMode};
+    pub use crate::animations::utils::{
+        AnimationConfig, AnimationMode, Bounds, LoopMode, LoopVelocity, MotionPath,
+    };
     pub use crate::animations::{
-        colors::Color, spring::Spring, transform::Transform, tween::Tween,
+        colors::{Color, ColorParseError, ColorSpace},
+        decay::Decay,
+        length::{Length, LengthUnit},
+        rotation::Rotation3D,
+        spring::{Spring, SpringCompletion, SpringSolver},
+        transform::Transform,
+        tween::{JumpMode, NamedEasing, Tween},
+        vector::Vector2D,
     };
+    pub use crate::components::{AnimatePresence, AnimatedValue};
     #[cfg(feature = "transitions")]
     pub use crate::dioxus_motion_transitions_macro::MotionTransitions;
+    pub use crate::layout::{LayoutMotion, use_layout_motion};
     #[cfg(feature = "transitions")]
     pub use crate::transitions::page_transitions::{AnimatableRoute, AnimatedOutlet};
     #[cfg(feature = "transitions")]
-    pub use crate::transitions::utils::TransitionVariant;
+    pub use crate::transitions::utils::{Direction, TransitionVariant};
     pub use crate::{
-        AnimationManager, AnimationSequence, Duration, Time, TimeProvider, use_motion,
+        AnimationEvent, AnimationFrameStatus, AnimationManager, AnimationSequence,
+        AnimationSnapshot, Combined, Duration, KeyframeAnimation, MotionGroup, MotionMap,
+        MotionToggle, ScrollMotion, Time, TimeProvider, TransformAnimationConfig, TransformMotion,
+        combine, use_motion, use_motion_group, use_motion_manual, use_motion_map, use_motion_to,
+        use_motion_toggle, use_scroll_motion, use_transform_motion,
     };
 }
 
 pub type Time = MotionTime;
 
+/// When a sequence step begins relative to the step before it
+#[derive(Clone, Copy)]
+enum StepStart {
+    /// Once the previous step finishes, after waiting out this gap
+    After(Duration),
+    /// As soon as the previous step reaches this progress, cutting it short
+    AtProgress(f32),
+}
+
 #[derive(Clone)]
 #[allow(dead_code)]
 struct AnimationStep<T: Animatable> {
     target: T,
+    relative: bool, // Whether `target` is a delta from the previous step's target
     config: Arc<AnimationConfig>,
+    start: StepStart,
     // Add predicted next state for smoother transitions
     predicted_next: Option<T>,
 }
 
+impl<T: Animatable> AnimationStep<T> {
+    /// Config used to run this step, with the step's gap folded into its start delay
+    fn start_config(&self) -> AnimationConfig {
+        let mut config = (*self.config).clone();
+        if let StepStart::After(gap) = self.start {
+            config.delay += gap;
+        }
+        config
+    }
+
+    /// Target of this step, given the target of the step before it
+    fn resolve(&self, previous: T) -> T {
+        if self.relative {
+            previous.add(&self.target)
+        } else {
+            self.target
+        }
+    }
+}
+
 // Use a static array instead of Vec for small sequences
 type AnimationSteps<T> = SmallVec<[AnimationStep<T>; 8]>;
 
 pub struct AnimationSequence<T: Animatable> {
     steps: AnimationSteps<T>,
     current_step: u8,
-    on_complete: Option<Box<dyn FnOnce()>>,
+    on_complete: Option<Box<dyn FnOnce(T)>>,
+    on_step: Option<Rc<dyn Fn(usize)>>,
+    // How the sequence as a whole repeats, and how many full passes are done
+    loop_mode: LoopMode,
+    completed_loops: u32,
     // Add capacity hint for better allocation
     capacity_hint: u8,
 }
@@ -94,6 +163,9 @@ impl<T: Animatable> Clone for AnimationSequence<T> {
             steps: self.steps.clone(),
             current_step: self.current_step,
             on_complete: None,
+            on_step: self.on_step.clone(),
+            loop_mode: self.loop_mode,
+            completed_loops: self.completed_loops,
             capacity_hint: self.capacity_hint,
         }
     }
@@ -109,6 +181,9 @@ impl<T: Animatable> AnimationSequence<T> {
             steps: SmallVec::with_capacity(capacity as usize),
             current_step: 0,
             on_complete: None,
+            on_step: None,
+            loop_mode: LoopMode::None,
+            completed_loops: 0,
             capacity_hint: capacity,
         }
     }
@@ -118,24 +193,142 @@ impl<T: Animatable> AnimationSequence<T> {
         self.steps.reserve(additional as usize);
     }
 
-    pub fn then(mut self, target: T, config: AnimationConfig) -> Self {
+    pub fn then(self, target: T, config: AnimationConfig) -> Self {
+        self.then_after(target, config, Duration::default())
+    }
+
+    /// Adds a step that waits for `delay` after the previous step finishes before it begins
+    pub fn then_after(self, target: T, config: AnimationConfig, delay: Duration) -> Self {
+        self.push_step(target, false, config, StepStart::After(delay))
+    }
+
+    /// Adds a step that moves `delta` on from the previous step's target
+    ///
+    /// The target is worked out when the step begins, with [`Animatable::add`], so steps
+    /// like "then 20 more" don't need the absolute position up front. A relative first
+    /// step starts from the value the sequence begins at. When the sequence loops, the
+    /// first step of each later pass follows on from the last step's target.
+    pub fn then_relative(self, delta: T, config: AnimationConfig) -> Self {
+        self.push_step(delta, true, config, StepStart::After(Duration::default()))
+    }
+
+    /// Adds a step that takes over once the previous step reaches `progress` (0.0 to 1.0)
+    ///
+    /// The running step is cut short and the value heads for this step's target from
+    /// wherever it is, springs keeping their momentum, so the two motions blend into one.
+    /// `progress` is measured like [`Motion::get_progress`]. `on_step` fires for the step
+    /// that was cut short at the handover, but its config's `on_complete` doesn't since
+    /// it never finished. The last step always plays to the end before `on_complete`.
+    pub fn then_overlapping(self, target: T, config: AnimationConfig, progress: f32) -> Self {
+        self.push_step(
+            target,
+            false,
+            config,
+            StepStart::AtProgress(progress.clamp(0.0, 1.0)),
+        )
+    }
+
+    fn push_step(
+        mut self,
+        target: T,
+        relative: bool,
+        config: AnimationConfig,
+        start: StepStart,
+    ) -> Self {
         let predicted_next = self
             .steps
             .last()
+            .filter(|last_step| !last_step.relative && !relative)
             .map(|last_step| last_step.target.interpolate(&target, 0.5));
 
         self.steps.push(AnimationStep {
             target,
+            relative,
             config: Arc::new(config),
+            start,
             predicted_next,
         });
         self
     }
 
-    pub fn on_complete<F: FnOnce() + 'static>(mut self, f: F) -> Self {
+    pub fn on_complete<F: FnOnce() + 'static>(self, f: F) -> Self {
+        self.on_complete_with(move |_| f())
+    }
+
+    /// Sets a callback fired with the value the sequence finished at, for targets
+    /// computed at runtime that the callback needs to know about
+    pub fn on_complete_with<F: FnOnce(T) + 'static>(mut self, f: F) -> Self {
         self.on_complete = Some(Box::new(f));
         self
     }
+
+    /// Sets a callback fired with the index of each step as it finishes.
+    /// It runs before the next step starts, and before `on_complete` for the final step.
+    pub fn on_step<F: Fn(usize) + 'static>(mut self, f: F) -> Self {
+        self.on_step = Some(Rc::new(f));
+        self
+    }
+
+    /// Repeats the whole sequence from its first step after the last one finishes
+    ///
+    /// `LoopMode::Times` counts full passes through the sequence. Only `Infinite` and
+    /// `Times` repeat a sequence; other modes play it once.
+    pub fn with_loop(mut self, mode: LoopMode) -> Self {
+        self.loop_mode = mode;
+        self
+    }
+
+    /// Builds a shake: `count` quick swings either side of the starting value that shrink
+    /// evenly to nothing, then a settle back where it began
+    ///
+    /// `intensity` is the offset of the first swing, e.g. `10.0` for a 10px horizontal
+    /// jitter on a rejected input. The steps are relative, so the value shakes around
+    /// wherever it is when the sequence starts and ends back there.
+    pub fn shake(intensity: T, count: u8) -> Self {
+        Self::oscillate(intensity, count, Duration::from_millis(50), |swing| {
+            1.0 - f32::from(swing) / f32::from(count)
+        })
+    }
+
+    /// Builds a wobble: `count` slower swings either side of the starting value, each half
+    /// the size of the one before, then a settle back where it began
+    ///
+    /// Suits rotation and scale, e.g. `wobble(15.0, 4)` for a jelly-like tilt in degrees.
+    /// Like [`shake`](Self::shake), it moves relative to the value it starts from.
+    pub fn wobble(intensity: T, count: u8) -> Self {
+        Self::oscillate(intensity, count, Duration::from_millis(150), |swing| {
+            0.5f32.powi(i32::from(swing))
+        })
+    }
+
+    /// Relative steps to alternating offsets of `intensity` scaled by `falloff`, then back
+    fn oscillate(intensity: T, count: u8, swing: Duration, falloff: impl Fn(u8) -> f32) -> Self {
+        let config = AnimationConfig::new(AnimationMode::Tween(
+            Tween::new(swing).with_easing(NamedEasing::EaseInOut),
+        ));
+        let mut sequence = Self::with_capacity(count.saturating_add(1));
+        let mut previous = T::zero();
+        for index in 0..count {
+            let side = if index & 1 == 0 { 1.0 } else { -1.0 };
+            let offset = intensity.scale(side * falloff(index));
+            sequence = sequence.then_relative(offset.sub(&previous), config.clone());
+            previous = offset;
+        }
+        sequence.then_relative(T::zero().sub(&previous), config)
+    }
+
+    /// Records a finished pass and reports whether the sequence should play again
+    fn start_next_loop(&mut self) -> bool {
+        let repeat = match self.loop_mode {
+            LoopMode::Infinite => true,
+            LoopMode::Times(count) => self.completed_loops.saturating_add(1) < count,
+            _ => false,
+        };
+        if repeat {
+            self.completed_loops = self.completed_loops.saturating_add(1);
+        }
+        repeat
+    }
 }
 
 impl<T: Animatable> Default for AnimationSequence<T> {
@@ -144,11 +337,104 @@ impl<T: Animatable> Default for AnimationSequence<T> {
             steps: AnimationSteps::new(),
             current_step: 0,
             on_complete: None,
+            on_step: None,
+            loop_mode: LoopMode::None,
+            completed_loops: 0,
             capacity_hint: 0,
         }
     }
 }
 
+/// Distance and speed below which a spring counts as settled, unless the config overrides it
+const SPRING_REST_EPSILON: f32 = 0.001;
+
+/// How long after the last `set_value` a drag's velocity is still handed to a new animation;
+/// a pointer held still sends no more moves, so older estimates are stale
+const DRAG_VELOCITY_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// How long after the last retarget a followed target's velocity is still trusted for lead;
+/// a target that stopped moving sends no more updates, so the spring heads back to it
+const LEAD_VELOCITY_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// A target followed with `AnimationConfig::lead_time`
+#[derive(Clone, Copy)]
+struct Lead<T> {
+    target: T,   // Where the target actually is, rather than the point aimed at
+    at: Instant, // When it was last moved
+    velocity: T, // How fast it was moving
+}
+
+/// What a single call to [`AnimationManager::update_status`] did
+///
+/// Tells apart the states `update`'s `bool` lumps together, so side effects can be tied to
+/// the frame an animation finishes or loops without comparing values between frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnimationFrameStatus {
+    /// Nothing was running, so nothing happened
+    Idle,
+    /// Running but holding for its delay, or the repeat delay between loops
+    Delayed,
+    /// Running and moving towards its target; a paused animation also reports this
+    Active,
+    /// Finished on this frame, after firing its completion callbacks
+    CompletedThisFrame,
+    /// Reached the end of an iteration on this frame and started the next loop
+    LoopedThisFrame,
+}
+
+impl AnimationFrameStatus {
+    /// Whether the animation still needs frames, what `update` returns
+    pub fn is_animating(self) -> bool {
+        !matches!(self, Self::Idle | Self::CompletedThisFrame)
+    }
+}
+
+/// Lifecycle event published to the receivers handed out by [`AnimationManager::events`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnimationEvent {
+    /// An animation, sequence or keyframe animation started
+    Started,
+    /// The animation finished, including by [`AnimationManager::finish`]. A sequence
+    /// completes once, after its last step
+    Completed,
+    /// An iteration ended and the next loop started, with the number of loops so far
+    Looped(u32),
+    /// A running animation was stopped, or replaced by a new one before it finished
+    Interrupted,
+}
+
+/// Saved state of an animation, taken with [`AnimationManager::snapshot`]
+///
+/// Captures where the value is, where it's heading and how fast, and how far through the
+/// animation it got, so it can be put back with [`AnimationManager::restore`] for undo and
+/// redo, or persisted across a reload with the `serde` feature. The config isn't part of the
+/// snapshot, and neither are sequences or keyframes: a restored animation carries on with
+/// the config the motion has when it's restored.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnimationSnapshot<T> {
+    /// Value the animation started from
+    pub initial: T,
+    /// Current value
+    pub current: T,
+    /// Value the animation is heading to
+    pub target: T,
+    /// Current velocity
+    pub velocity: T,
+    /// Time spent animating, not counting the delay
+    pub elapsed: Duration,
+    /// Time spent waiting out the delay
+    pub delay_elapsed: Duration,
+    /// Whether the animation has looped, so the delay being waited out is the repeat delay
+    pub repeating: bool,
+    /// Loops completed so far
+    pub current_loop: u32,
+    /// Whether the animation was running
+    pub running: bool,
+    /// Whether the animation was paused
+    pub paused: bool,
+}
+
 #[derive(Clone)]
 pub struct Motion<T: Animatable> {
     initial: T,
@@ -156,13 +442,27 @@ pub struct Motion<T: Animatable> {
     target: T,
     velocity: T,
     running: bool,
+    paused: bool,
     elapsed: Duration,
     delay_elapsed: Duration, // Add this field
-    current_loop: u8,
+    repeating: bool, // Whether a loop restarted, so `repeat_delay` applies instead of `delay`
+    current_loop: u32,
     config: Arc<AnimationConfig>,
     sequence: Option<Arc<AnimationSequence<T>>>,
     reverse: bool, // New field to track direction for alternating animations
     keyframe_animation: Option<Arc<KeyframeAnimation<T>>>,
+    scheduled: Cell<bool>, // Whether the shared ticker is currently driving this motion
+    manual: bool,          // Stepped by the caller, so never handed to the ticker
+    // The bounded value last shown and the unbounded one the physics carries on from
+    unbounded: Option<(T, T)>,
+    // When set_value last moved the value, while `velocity` holds the drag's estimate
+    dragged_at: Option<Instant>,
+    lead: Option<Lead<T>>,
+    events: Vec<UnboundedSender<AnimationEvent>>,
+    clock: fn() -> Instant, // Where drag and lead timeouts read the time, `Time::now` by default
+    on_update: Option<OnUpdate<T>>,
+    on_complete_with: Option<OnCompleteWith<T>>, // Waits on the running animation only
+    bounds: Option<Bounds<T>>,
 }
 
 impl<T: Animatable> Motion<T> {
@@ -173,32 +473,226 @@ impl<T: Animatable> Motion<T> {
             target: initial,
             velocity: T::zero(),
             running: false,
+            paused: false,
             elapsed: Duration::default(),
             current_loop: 0,
             config: Arc::new(AnimationConfig::default()),
             sequence: None,
             reverse: false,
             delay_elapsed: Duration::default(),
+            repeating: false,
             keyframe_animation: None,
+            scheduled: Cell::new(false),
+            manual: false,
+            unbounded: None,
+            dragged_at: None,
+            lead: None,
+            events: Vec::new(),
+            clock: Time::now,
+            on_update: None,
+            on_complete_with: None,
+            bounds: None,
         }
     }
 
+    /// Reads the time from `P` instead of the platform clock
+    ///
+    /// The clock decides how long ago the value was last dragged or a followed target last
+    /// moved. With [`TestTime`], tests control those timeouts with [`TestTime::advance`]
+    /// and step the motion themselves, e.g. as the `Signal` of a
+    /// [`use_motion_manual`] motion, since the shared ticker measures frames with the
+    /// platform clock.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::{Duration, Motion, TestTime, TimeProvider};
+    /// let mut motion = Motion::new(0.0f32).with_time_provider::<TestTime>();
+    /// motion.set_value(0.0);
+    /// TestTime::advance(Duration::from_millis(10));
+    /// motion.set_value(5.0);
+    /// assert_eq!(motion.snapshot().velocity, 500.0);
+    /// ```
+    pub fn with_time_provider<P: TimeProvider>(mut self) -> Self {
+        self.clock = P::now;
+        self
+    }
+
+    /// Current time on this motion's clock
+    fn now(&self) -> Instant {
+        (self.clock)()
+    }
+
+    /// Whether `set_value` moved the value within [`DRAG_VELOCITY_TIMEOUT`] of `now`, so
+    /// the drag's velocity still counts
+    fn dragging(&self, now: Instant) -> bool {
+        self.dragged_at
+            .is_some_and(|at| now.saturating_duration_since(at) <= DRAG_VELOCITY_TIMEOUT)
+    }
+
+    /// Starts animating from the current value towards `target`
+    ///
+    /// Retargeting a running animation with a spring keeps its current velocity, so values
+    /// that follow a moving target don't stutter. Turn this off with
+    /// [`AnimationConfig::with_preserve_velocity_on_retarget`].
     pub fn animate_to(&mut self, target: T, config: AnimationConfig) {
+        let now = self.now();
+        let target = self.lead_target(target, &config, now);
+        self.start(target, config, now);
+    }
+
+    /// Same as [`Motion::animate_to`], but leaves a running animation alone when it is
+    /// already heading to `target` (within `T::epsilon()`), returning whether a new
+    /// animation was started
+    ///
+    /// For calls repeated with the same target, such as on every render, that shouldn't
+    /// restart the animation from zero elapsed time. A skipped call drops its config,
+    /// callbacks included. Paused animations, sequences and keyframes are always replaced.
+    pub fn try_animate_to(&mut self, target: T, config: AnimationConfig) -> bool {
+        self.try_animate_to_at(target, config, self.now())
+    }
+
+    fn try_animate_to_at(&mut self, target: T, config: AnimationConfig, now: Instant) -> bool {
+        let target = self.lead_target(target, &config, now);
+        let same_target = target.sub(&self.target).magnitude() < T::epsilon();
+        if same_target
+            && self.running
+            && !self.paused
+            && self.sequence.is_none()
+            && self.keyframe_animation.is_none()
+        {
+            return false;
+        }
+        self.start(target, config, now);
+        true
+    }
+
+    /// Starts animating towards `target`, already adjusted for any lead
+    fn start(&mut self, target: T, config: AnimationConfig, now: Instant) {
+        let dragging = self.dragging(now);
+        self.dragged_at = None;
+        let keep_velocity = (self.running || dragging)
+            && config.preserve_velocity_on_retarget
+            && matches!(config.mode, AnimationMode::Spring(_));
+        self.publish_start();
+        self.settle_shown();
         self.sequence = None;
         self.initial = self.current;
         self.target = target;
         self.config = Arc::new(config);
         self.running = true;
+        self.paused = false;
         self.elapsed = Duration::default();
         self.delay_elapsed = Duration::default();
-        self.velocity = T::zero();
+        self.repeating = false;
+        if !keep_velocity {
+            self.velocity = self.loop_start_velocity();
+        }
         self.current_loop = 0;
     }
 
+    /// Moves the target of the animation in place, keeping its config, elapsed time and velocity
+    ///
+    /// Meant for the hot path of following a moving target, such as the cursor, on every
+    /// event: unlike `animate_to` it doesn't allocate a new config. Springs carry on smoothly
+    /// towards the new target, while tweens jump to the matching point of their new path.
+    /// When idle, an animation towards `target` starts with the last config used.
+    pub fn retarget(&mut self, target: T) {
+        // Output easing is relative to the start and target, so carry on from what was shown
+        if self.running && self.config.output_easing.is_some() {
+            self.settle_shown();
+            self.initial = self.current;
+        }
+        // Skips reading the clock on this hot path unless there's a lead to estimate
+        self.target = if self.config.lead_time.is_zero() {
+            self.lead = None;
+            target
+        } else {
+            let config = self.config.clone();
+            let now = self.now();
+            self.lead_target(target, &config, now)
+        };
+        if !self.running {
+            self.publish_start();
+            self.initial = self.current;
+            self.running = true;
+            self.paused = false;
+            self.elapsed = Duration::default();
+            self.delay_elapsed = Duration::default();
+            self.repeating = false;
+            self.current_loop = 0;
+        }
+    }
+
+    /// Where a spring following `target` aims: `lead_time` further along the target's own
+    /// motion since the previous call, or the target itself when there's no lead to apply
+    fn lead_target(&mut self, target: T, config: &AnimationConfig, now: Instant) -> T {
+        if config.lead_time.is_zero() || !matches!(config.mode, AnimationMode::Spring(_)) {
+            self.lead = None;
+            return target;
+        }
+        let velocity = self.lead.map_or(T::zero(), |lead| {
+            let dt = now.saturating_duration_since(lead.at);
+            if dt > LEAD_VELOCITY_TIMEOUT {
+                T::zero()
+            } else if dt.is_zero() {
+                // Several moves within one instant: keep the last estimate
+                lead.velocity
+            } else {
+                target.sub(&lead.target).scale(1.0 / dt.as_secs_f32())
+            }
+        });
+        self.lead = Some(Lead {
+            target,
+            at: now,
+            velocity,
+        });
+        target.add(&velocity.scale(config.lead_time.as_secs_f32()))
+    }
+
+    /// Animates to the current value plus `delta`
+    pub fn animate_by(&mut self, delta: T, config: AnimationConfig) {
+        self.animate_to(self.current.add(&delta), config);
+    }
+
+    /// Moves the value by `delta` without touching the target, carrying on from there
+    ///
+    /// A running tween starts over from the new value, and a spring keeps its velocity.
+    fn shift_by(&mut self, delta: T) {
+        self.current = self.current.add(&delta);
+        self.initial = self.current;
+        self.elapsed = Duration::default();
+        self.unbounded = None;
+    }
+
+    /// Same as [`Motion::animate_to`], but starts from `initial_velocity` instead of rest
+    pub fn animate_to_with_velocity(
+        &mut self,
+        target: T,
+        config: AnimationConfig,
+        initial_velocity: T,
+    ) {
+        self.animate_to(target, config);
+        self.velocity = initial_velocity;
+    }
+
+    /// Flings the value with `velocity` and lets it glide to a stop
+    ///
+    /// With an [`AnimationMode::Decay`] config the value slows down under friction and
+    /// comes to rest wherever its momentum carries it. The target is set to that projected
+    /// resting point, so other modes head for the same place, springs carrying the velocity.
+    pub fn animate_decay(&mut self, velocity: T, config: AnimationConfig) {
+        let decay = match config.mode {
+            AnimationMode::Decay(decay) => decay,
+            _ => Decay::default(),
+        };
+        let target = self.current.add(&decay.projected_distance(velocity));
+        self.animate_to_with_velocity(target, config, velocity);
+    }
+
     pub fn animate_sequence(&mut self, sequence: AnimationSequence<T>) {
         if let Some(first_step) = sequence.steps.first() {
             // This approach doesn't correctly initialize the sequence state
-            self.animate_to(first_step.target, (*first_step.config).clone());
+            self.animate_to(first_step.resolve(self.current), first_step.start_config());
 
             // Start with current_step as 0 instead of -1 to fix indexing
             let mut new_sequence = sequence;
@@ -207,10 +701,25 @@ impl<T: Animatable> Motion<T> {
         }
     }
 
+    /// Plays `animation` through its keyframes
+    ///
+    /// The keyframes take the rest of their timing from the motion's current config, as a
+    /// tween would: its delay, loop mode, repeat delay and playback speed all apply.
     pub fn animate_keyframes(&mut self, animation: KeyframeAnimation<T>) {
+        if let (Some(first), Some(last)) = (animation.keyframes.first(), animation.keyframes.last())
+        {
+            self.initial = first.value;
+            self.target = last.value;
+        }
+        self.publish_start();
         self.keyframe_animation = Some(Arc::new(animation));
+        self.sequence = None;
         self.running = true;
+        self.paused = false;
+        self.current_loop = 0;
         self.elapsed = Duration::default();
+        self.delay_elapsed = Duration::default();
+        self.repeating = false;
         self.velocity = T::zero();
     }
 
@@ -222,6 +731,13 @@ impl<T: Animatable> Motion<T> {
         self.running || self.sequence.is_some() || self.keyframe_animation.is_some()
     }
 
+    /// Whether the animation is still waiting out its delay, or the repeat delay between loops
+    ///
+    /// Only true while running, and stays true while paused during the delay.
+    pub fn is_delayed(&self) -> bool {
+        self.running && self.delay_elapsed < self.current_delay()
+    }
+
     pub fn reset(&mut self) {
         self.stop();
         self.current = self.initial;
@@ -229,11 +745,114 @@ impl<T: Animatable> Motion<T> {
     }
 
     pub fn stop(&mut self) {
+        self.interrupt();
+        self.settle_shown();
+        self.halt();
+    }
+
+    /// Stops without reporting an interruption, for animations that ended on their own
+    fn halt(&mut self) {
         self.running = false;
+        self.paused = false;
         self.current_loop = 0;
         self.velocity = T::zero();
         self.sequence = None;
         self.keyframe_animation = None;
+        self.dragged_at = None;
+        self.lead = None;
+        self.repeating = false;
+    }
+
+    /// Captures the state of the animation, see [`AnimationSnapshot`]
+    pub fn snapshot(&self) -> AnimationSnapshot<T> {
+        AnimationSnapshot {
+            initial: self.initial,
+            current: self.current,
+            target: self.target,
+            velocity: self.velocity,
+            elapsed: self.elapsed,
+            delay_elapsed: self.delay_elapsed,
+            repeating: self.repeating,
+            current_loop: self.current_loop,
+            running: self.running,
+            paused: self.paused,
+        }
+    }
+
+    /// Puts back a state captured with [`Motion::snapshot`], replacing whatever was running
+    pub fn restore(&mut self, snapshot: AnimationSnapshot<T>) {
+        self.stop();
+        self.unbounded = None;
+        self.initial = snapshot.initial;
+        self.current = snapshot.current;
+        self.target = snapshot.target;
+        self.velocity = snapshot.velocity;
+        self.elapsed = snapshot.elapsed;
+        self.delay_elapsed = snapshot.delay_elapsed;
+        self.repeating = snapshot.repeating;
+        self.current_loop = snapshot.current_loop;
+        self.running = snapshot.running;
+        self.paused = snapshot.paused;
+    }
+
+    /// Jumps to `value` without animating, stopping whatever was running
+    ///
+    /// Successive calls estimate the velocity the value is being moved at, which
+    /// `get_velocity` reports and a spring started with `animate_to` shortly after carries
+    /// on with, so releasing a drag flings the value instead of dropping it from rest.
+    pub fn set_value(&mut self, value: T) {
+        self.set_value_at(value, self.now());
+    }
+
+    fn set_value_at(&mut self, value: T, now: Instant) {
+        let velocity = self.dragged_at.map_or(T::zero(), |at| {
+            let dt = now.saturating_duration_since(at).as_secs_f32();
+            if dt > 0.0 {
+                value.sub(&self.current).scale(1.0 / dt)
+            } else {
+                // Several moves within one instant: keep the last estimate
+                self.velocity
+            }
+        });
+        self.stop();
+        self.initial = value;
+        self.current = value;
+        self.target = value;
+        self.unbounded = None;
+        self.velocity = velocity;
+        self.dragged_at = Some(now);
+    }
+
+    /// Skips straight to the end of the animation and stops it
+    ///
+    /// The value jumps to the current target (the last step's target for sequences) and
+    /// `on_complete` fires, followed by the sequence's `on_complete`. Any remaining loops
+    /// are dropped. Does nothing when no animation is running.
+    pub fn finish(&mut self) {
+        if !self.is_running() {
+            return;
+        }
+
+        let mut on_sequence_complete = None;
+        if let Some(sequence) = self.sequence.as_mut() {
+            let sequence = Arc::make_mut(sequence);
+            let remaining = sequence
+                .steps
+                .iter()
+                .skip(sequence.current_step as usize + 1);
+            self.target = remaining.fold(self.target, |previous, step| step.resolve(previous));
+            on_sequence_complete = sequence.on_complete.take();
+        }
+
+        let previous = self.current;
+        self.current = self.target;
+        self.notify_update(previous);
+        self.halt();
+
+        self.notify_complete();
+        if let Some(on_complete) = on_sequence_complete {
+            on_complete(self.current);
+        }
     }
 
     pub fn delay(&mut self, duration: Duration) {
@@ -242,89 +861,338 @@ impl<T: Animatable> Motion<T> {
         self.config = Arc::new(config);
     }
 
+    /// Number of loops completed so far in the current animation
+    ///
+    /// Alternating and ping-pong loops count each leg separately.
+    pub fn current_loop(&self) -> u32 {
+        self.current_loop
+    }
+
+    /// Normalized progress of the current animation, from 0.0 to 1.0
+    ///
+    /// Tweens and keyframe animations report raw time progress (`elapsed / duration`)
+    /// before easing, so it advances linearly even when the value does not. Springs have
+    /// no fixed duration, so they estimate progress from the share of the distance between
+    /// `initial` and `target` already covered, clamped to 1.0 while overshooting.
+    /// An animation that has stopped at its target reports 1.0.
+    pub fn get_progress(&self) -> f32 {
+        let remaining = self.target.sub(&self.current).magnitude();
+        if !self.is_running() && remaining <= T::epsilon() {
+            return 1.0;
+        }
+
+        let duration = match (&self.keyframe_animation, &self.config.mode) {
+            (Some(animation), _) => Some(animation.duration),
+            (None, AnimationMode::Tween(tween)) => Some(tween.duration),
+            (None, AnimationMode::Spring(_) | AnimationMode::Decay(_)) => None,
+        };
+
+        if let Some(duration) = duration {
+            let duration = duration.as_secs_f32();
+            return if duration > 0.0 {
+                (self.elapsed.as_secs_f32() / duration).clamp(0.0, 1.0)
+            } else {
+                1.0
+            };
+        }
+
+        let distance = self.target.sub(&self.initial).magnitude();
+        if distance > 0.0 {
+            (1.0 - remaining / distance).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+
+    /// Estimated time until the running animation completes, including any delay that
+    /// hasn't run out yet and the playback speed
+    ///
+    /// Tweens and keyframe animations know their remaining time exactly, counting any
+    /// loops still to play. Springs have no fixed duration, so they report the share of
+    /// [`Spring::estimated_settling_time`] left by distance. Sequences only count their
+    /// current step. Returns `None` when idle, when looping forever, or at a speed of zero.
+    pub fn time_remaining(&self) -> Option<Duration> {
+        let loops_forever = matches!(
+            self.config.loop_mode,
+            Some(LoopMode::Infinite | LoopMode::Alternate | LoopMode::PingPong)
+        ) || self
+            .sequence
+            .as_ref()
+            .is_some_and(|sequence| sequence.loop_mode == LoopMode::Infinite);
+        let speed = self.playback_speed(motion_config());
+        if !self.is_running() || loops_forever || speed <= 0.0 {
+            return None;
+        }
+
+        let legs = match self.config.loop_mode {
+            Some(LoopMode::Times(count)) => count,
+            Some(LoopMode::AlternateTimes(count) | LoopMode::PingPongTimes(count)) => {
+                count.saturating_mul(2)
+            }
+            _ => 1,
+        };
+        let legs_after_this = legs.saturating_sub(self.current_loop).saturating_sub(1);
+
+        let (leg, this_leg) = match (&self.keyframe_animation, &self.config.mode) {
+            (Some(animation), _) => (
+                animation.duration,
+                animation.duration.saturating_sub(self.elapsed),
+            ),
+            (None, AnimationMode::Tween(tween)) => {
+                (tween.duration, tween.duration.saturating_sub(self.elapsed))
+            }
+            (None, AnimationMode::Spring(spring)) => {
+                let settling = spring.estimated_settling_time();
+                let left = settling.as_secs_f32() * (1.0 - self.get_progress());
+                let this_leg = Duration::try_from_secs_f32(left).unwrap_or(settling);
+                (settling, this_leg)
+            }
+            (None, AnimationMode::Decay(decay)) => {
+                // Time for the current speed to fall to the point the decay stops
+                let rest = self.completion_epsilon(T::epsilon()).max(f32::MIN_POSITIVE);
+                let speed = self.velocity.magnitude().max(rest);
+                let left = (speed / rest).ln() / decay.friction.max(f32::EPSILON);
+                let this_leg = Duration::try_from_secs_f32(left).unwrap_or(Duration::MAX);
+                (this_leg, this_leg)
+            }
+        };
+        let delay = self.current_delay().saturating_sub(self.delay_elapsed);
+
+        let remaining = delay.saturating_add(this_leg).saturating_add(
+            leg.saturating_add(self.config.repeat_delay)
+                .saturating_mul(legs_after_this),
+        );
+        Some(Duration::try_from_secs_f32(remaining.as_secs_f32() / speed).unwrap_or(Duration::MAX))
+    }
+
+    /// Jumps to `progress` (clamped to 0.0..=1.0) between `initial` and `target` without
+    /// playing over time
+    ///
+    /// Meant for scroll-linked or scrubbed animations: start an animation once to set its
+    /// range and config, then seek to a new progress every frame. Seeking stops time-driven
+    /// playback, along with any sequence or keyframe animation. Tweens apply their easing at
+    /// the new point in time; springs have no timeline, so they fall back to linear
+    /// interpolation between `initial` and `target`.
+    pub fn seek(&mut self, progress: f32) {
+        let progress = progress.clamp(0.0, 1.0);
+        self.running = false;
+        self.paused = false;
+        self.sequence = None;
+        self.keyframe_animation = None;
+        self.velocity = T::zero();
+
+        let previous = self.current;
+        let config = self.config.clone();
+        let t = match &config.mode {
+            AnimationMode::Tween(tween) => {
+                self.elapsed = tween.duration.mul_f32(progress);
+                tween.easing.ease(progress, 0.0, 1.0, 1.0)
+            }
+            AnimationMode::Spring(_) | AnimationMode::Decay(_) => progress,
+        };
+        self.current = self.point_on_path(t);
+        self.notify_update(previous);
+    }
+
+    /// Plays the current animation back towards where it started
+    ///
+    /// Works both in flight and after completion. Springs keep their velocity, and
+    /// tweens jump to the mirrored point in time so the value continues without a jump
+    /// (exact for symmetric easings such as linear or ease-in-out). Any sequence or
+    /// keyframe animation is left behind.
+    pub fn reverse(&mut self) {
+        std::mem::swap(&mut self.initial, &mut self.target);
+        if let AnimationMode::Tween(tween) = &self.config.mode {
+            self.elapsed = tween
+                .duration
+                .saturating_sub(self.elapsed.min(tween.duration));
+        }
+        self.delay_elapsed = self.current_delay();
+        self.sequence = None;
+        self.keyframe_animation = None;
+        self.running = true;
+    }
+
+    /// Freezes the animation in place, keeping its elapsed time, velocity and value
+    pub fn pause(&mut self) {
+        if self.is_running() {
+            self.paused = true;
+        }
+    }
+
+    /// Continues a paused animation from exactly where it left off
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
     fn update(&mut self, dt: f32) -> bool {
-        if !self.running && self.sequence.is_none() && self.keyframe_animation.is_none() {
-            return false;
+        self.update_status(dt).is_animating()
+    }
+
+    /// Steps the animation by `dt` seconds, reporting what happened on this frame
+    pub fn update_status(&mut self, dt: f32) -> AnimationFrameStatus {
+        self.advance(dt, motion_config(), prefers_reduced_motion)
+    }
+
+    /// Steps the animation by `dt` seconds under the app-wide `global` settings, asking
+    /// `reduced_motion` whether the user prefers reduced motion
+    fn advance(
+        &mut self,
+        dt: f32,
+        global: MotionConfig,
+        reduced_motion: fn() -> bool,
+    ) -> AnimationFrameStatus {
+        if !self.is_running() {
+            return AnimationFrameStatus::Idle;
         }
 
-        if let Some(sequence) = &self.sequence {
-            if !self.running {
-                // Current animation has completed, move to next step
-                let current_step = sequence.current_step;
-                let total_steps = sequence.steps.len();
-
-                // Check if there are more steps to animate
-                if current_step < (total_steps - 1) as u8 {
-                    // Changed this condition
-                    // Move to the next step
-                    let mut new_sequence = (**sequence).clone();
-                    new_sequence.current_step = current_step + 1;
-                    let next_step = current_step + 1;
-
-                    // Get the next step
-                    let step = &sequence.steps[next_step as usize];
-                    let target = step.target;
-                    let config = (*step.config).clone();
-                    self.sequence = Some(Arc::new(new_sequence));
-
-                    // Start the next animation
-                    self.initial = self.current; // Start from current position
-                    self.target = target;
-                    self.config = Arc::new(config);
-                    self.running = true;
-                    self.elapsed = Duration::default();
-                    self.delay_elapsed = Duration::default();
+        // A paused animation is still active, it just doesn't advance
+        if self.paused {
+            return AnimationFrameStatus::Active;
+        }
+
+        if !global.animations_enabled {
+            self.finish();
+            return AnimationFrameStatus::CompletedThisFrame;
+        }
+
+        // Only consulted before the animation has advanced, so the media query isn't run every frame
+        let starting = self.elapsed.is_zero() && self.delay_elapsed.is_zero();
+        if starting && self.config.respect_reduced_motion && reduced_motion() {
+            self.finish();
+            return AnimationFrameStatus::CompletedThisFrame;
+        }
+
+        let taking_over = self.running && self.overlapping_step_due();
+        if let Some(sequence) = self.sequence.as_mut()
+            && (!self.running || taking_over)
+        {
+            // Current animation has completed (or is being taken over), advance the
+            // sequence in place so its callbacks survive (cloning a sequence drops `on_complete`)
+            let sequence = Arc::make_mut(sequence);
+            let current_step = sequence.current_step;
+
+            if let Some(on_step) = &sequence.on_step {
+                on_step(current_step as usize);
+            }
+
+            // Check if there are more steps to animate, or another pass to play
+            let next_index = if current_step as usize + 1 < sequence.steps.len() {
+                Some(current_step as usize + 1)
+            } else if sequence.start_next_loop() {
+                Some(0)
+            } else {
+                None
+            };
+            let next_step = next_index.and_then(|index| {
+                sequence
+                    .steps
+                    .get(index)
+                    .map(|step| (index, step.resolve(self.target), step.start_config()))
+            });
+
+            if let Some((index, target, config)) = next_step {
+                sequence.current_step = index as u8;
+
+                // Start the next animation
+                self.settle_shown();
+                self.initial = self.current; // Start from current position
+                self.target = target;
+                self.config = Arc::new(config);
+                self.running = true;
+                self.elapsed = Duration::default();
+                self.delay_elapsed = Duration::default();
+                self.repeating = false;
+                if !(taking_over && self.config.preserve_velocity_on_retarget) {
                     self.velocity = T::zero();
+                }
 
-                    return true;
-                } else {
-                    // Sequence complete - we've reached the last step
-                    let mut sequence_clone = (**sequence).clone();
-                    if let Some(on_complete) = sequence_clone.on_complete.take() {
-                        on_complete();
-                    }
-                    self.sequence = None;
-                    self.stop();
-                    return false;
+                return AnimationFrameStatus::Active;
+            } else {
+                // Sequence complete - we've reached the last step
+                if let Some(on_complete) = sequence.on_complete.take() {
+                    on_complete(self.current);
                 }
+                self.sequence = None;
+                self.halt();
+                self.publish(AnimationEvent::Completed);
+                self.settle_on_complete_with();
+                return AnimationFrameStatus::CompletedThisFrame;
             }
         }
 
-        if let Some(_animation) = &self.keyframe_animation {
-            return self.update_keyframes(dt);
+        // Playback speed stretches or compresses time. Every frame is stepped however
+        // short, since high refresh rate displays and fixed-step loops hand out frames of a
+        // few milliseconds
+        let mut dt = dt * self.playback_speed(global);
+
+        // Hold at the initial value until the delay runs out, then spend whatever is
+        // left of this frame on the animation itself
+        let delay = self.current_delay();
+        if self.delay_elapsed < delay {
+            let remaining = delay - self.delay_elapsed;
+            let step = Duration::from_secs_f32(dt);
+            if step <= remaining {
+                self.delay_elapsed += step;
+                return AnimationFrameStatus::Delayed;
+            }
+            self.delay_elapsed = delay;
+            dt = (step - remaining).as_secs_f32();
         }
 
-        // Skip updates for imperceptible changes
-        const MIN_DELTA: f32 = 1.0 / 240.0; // ~4ms
-        if dt < MIN_DELTA {
-            return true;
+        if self.keyframe_animation.is_some() {
+            return self.update_keyframes(dt);
         }
 
-        if self.delay_elapsed < self.config.delay {
-            self.delay_elapsed += Duration::from_secs_f32(dt);
-            return true;
+        // A followed target that stopped moving: aim at it rather than ahead of it
+        let now = self.now();
+        if let Some(lead) = self
+            .lead
+            .take_if(|lead| now.saturating_duration_since(lead.at) > LEAD_VELOCITY_TIMEOUT)
+        {
+            self.target = lead.target;
         }
 
-        let completed = match self.config.mode {
+        let previous = self.current;
+        self.restore_unbounded();
+        let config = self.config.clone();
+        let completed = match &config.mode {
             AnimationMode::Spring(spring) => {
-                let spring_result = self.update_spring(spring, dt);
-                matches!(spring_result, SpringState::Completed)
+                let spring_result = match config.spring_solver {
+                    SpringSolver::Integrated => self.update_spring(*spring, dt),
+                    SpringSolver::Analytic => self.update_spring_analytic(*spring, dt),
+                };
+                if config.clamp_to_target {
+                    self.clamp_to_target();
+                }
+                matches!(spring_result, SpringState::Completed) || self.ping_pong_turn_due()
             }
             AnimationMode::Tween(tween) => self.update_tween(tween, dt),
+            AnimationMode::Decay(decay) => self.update_decay(*decay, dt),
         };
+        self.apply_bounds();
+        self.notify_update(previous);
+
+        // Settled on the point aimed at ahead of the target: carry on to the target itself
+        if let Some(lead) = self
+            .lead
+            .take_if(|lead| completed && lead.target.sub(&self.target).magnitude() >= T::epsilon())
+        {
+            self.target = lead.target;
+            return AnimationFrameStatus::Active;
+        }
 
-        if completed {
-            self.handle_completion()
+        if !completed {
+            AnimationFrameStatus::Active
+        } else if self.handle_completion() {
+            AnimationFrameStatus::LoopedThisFrame
         } else {
-            true
+            AnimationFrameStatus::CompletedThisFrame
         }
     }
 
     #[cfg(feature = "web")]
     fn update_spring(&mut self, spring: Spring, dt: f32) -> SpringState {
-        const VELOCITY_THRESHOLD: f32 = 0.001;
-        const POSITION_THRESHOLD: f32 = 0.001;
-
         // Cache frequently accessed values
         let stiffness = spring.stiffness;
         let damping = spring.damping;
@@ -339,9 +1207,7 @@ impl<T: Animatable> Motion<T> {
             let delta = self.target.sub(&self.current);
 
             // Early exit if movement is negligible
-            if delta.magnitude() < POSITION_THRESHOLD
-                && self.velocity.magnitude() < VELOCITY_THRESHOLD
-            {
+            if self.spring_at_rest(&spring) {
                 self.current = self.target;
                 self.velocity = T::zero();
                 return SpringState::Completed;
@@ -357,7 +1223,7 @@ impl<T: Animatable> Motion<T> {
             self.current = self.current.add(&self.velocity.scale(step_dt));
         }
 
-        self.check_spring_completion()
+        self.check_spring_completion(&spring)
     }
 
     #[cfg(not(feature = "web"))]
@@ -391,54 +1257,76 @@ impl<T: Animatable> Motion<T> {
             vel: self.velocity.clone(),
         };
 
-        // Perform RK4 integration
-        let k1 = derive(&state);
-        let k2 = derive(&State {
-            pos: state.pos.add(&k1.pos.scale(dt * 0.5)),
-            vel: state.vel.add(&k1.vel.scale(dt * 0.5)),
-        });
-        let k3 = derive(&State {
-            pos: state.pos.add(&k2.pos.scale(dt * 0.5)),
-            vel: state.vel.add(&k2.vel.scale(dt * 0.5)),
-        });
-        let k4 = derive(&State {
-            pos: state.pos.add(&k3.pos.scale(dt)),
-            vel: state.vel.add(&k3.vel.scale(dt)),
-        });
+        // Split long frames (or sped-up playback) into substeps to keep RK4 stable
+        const MAX_STEP: f32 = 1.0 / 60.0;
+        let steps = ((dt / MAX_STEP).ceil() as usize).max(1);
+        let dt = dt / steps as f32;
 
         const SIXTH: f32 = 1.0 / 6.0;
 
-        // Update position and velocity
-        self.current = state.pos.add(
-            &(k1.pos
-                .add(&k2.pos.scale(2.0))
-                .add(&k3.pos.scale(2.0))
-                .add(&k4.pos))
-            .scale(dt * SIXTH),
-        );
+        for _ in 0..steps {
+            // Perform RK4 integration
+            let k1 = derive(&state);
+            let k2 = derive(&State {
+                pos: state.pos.add(&k1.pos.scale(dt * 0.5)),
+                vel: state.vel.add(&k1.vel.scale(dt * 0.5)),
+            });
+            let k3 = derive(&State {
+                pos: state.pos.add(&k2.pos.scale(dt * 0.5)),
+                vel: state.vel.add(&k2.vel.scale(dt * 0.5)),
+            });
+            let k4 = derive(&State {
+                pos: state.pos.add(&k3.pos.scale(dt)),
+                vel: state.vel.add(&k3.vel.scale(dt)),
+            });
+
+            // Update position and velocity
+            state = State {
+                pos: state.pos.add(
+                    &(k1.pos
+                        .add(&k2.pos.scale(2.0))
+                        .add(&k3.pos.scale(2.0))
+                        .add(&k4.pos))
+                    .scale(dt * SIXTH),
+                ),
+                vel: state.vel.add(
+                    &(k1.vel
+                        .add(&k2.vel.scale(2.0))
+                        .add(&k3.vel.scale(2.0))
+                        .add(&k4.vel))
+                    .scale(dt * SIXTH),
+                ),
+            };
+        }
+
+        self.current = state.pos;
+        self.velocity = state.vel;
+
+        self.check_spring_completion(&spring)
+    }
+
+    /// Steps the spring with its exact solution, in one go however long the frame was
+    fn update_spring_analytic(&mut self, spring: Spring, dt: f32) -> SpringState {
+        let [[pos_from_pos, pos_from_vel], [vel_from_pos, vel_from_vel]] = spring.analytic_step(dt);
+        let displacement = self.current.sub(&self.target);
+        let velocity = self.velocity;
 
-        self.velocity = state.vel.add(
-            &(k1.vel
-                .add(&k2.vel.scale(2.0))
-                .add(&k3.vel.scale(2.0))
-                .add(&k4.vel))
-            .scale(dt * SIXTH),
+        self.current = self.target.add(
+            &displacement
+                .scale(pos_from_pos)
+                .add(&velocity.scale(pos_from_vel)),
         );
+        self.velocity = displacement
+            .scale(vel_from_pos)
+            .add(&velocity.scale(vel_from_vel));
 
-        self.check_spring_completion()
+        self.check_spring_completion(&spring)
     }
 
     // Helper method for spring completion check (shared between both implementations)
     #[inline(always)]
-    fn check_spring_completion(&mut self) -> SpringState {
-        const EPSILON: f32 = 0.001;
-        const EPSILON_SQ: f32 = EPSILON * EPSILON;
-
-        let velocity_sq = self.velocity.magnitude().powi(2);
-        let delta = self.target.sub(&self.current);
-        let delta_sq = delta.magnitude().powi(2);
-
-        if velocity_sq < EPSILON_SQ && delta_sq < EPSILON_SQ {
+    fn check_spring_completion(&mut self, spring: &Spring) -> SpringState {
+        if self.spring_at_rest(spring) {
             self.current = self.target;
             self.velocity = T::zero();
             SpringState::Completed
@@ -447,7 +1335,7 @@ impl<T: Animatable> Motion<T> {
         }
     }
 
-    fn update_tween(&mut self, tween: Tween, dt: f32) -> bool {
+    fn update_tween(&mut self, tween: &Tween, dt: f32) -> bool {
         // Use raw float operations instead of Duration for better performance
         let elapsed_secs = self.elapsed.as_secs_f32() + dt;
         self.elapsed = Duration::from_secs_f32(elapsed_secs);
@@ -470,18 +1358,86 @@ impl<T: Animatable> Motion<T> {
         }
 
         // Cache easing result and avoid unnecessary parameters
-        let eased_progress = (tween.easing)(progress, 0.0, 1.0, 1.0);
+        let eased_progress = tween.easing.ease(progress, 0.0, 1.0, 1.0);
 
         // Fast path for common cases
         match eased_progress {
             0.0 => self.current = self.initial,
             1.0 => self.current = self.target,
-            _ => self.current = self.initial.interpolate(&self.target, eased_progress),
+            _ => self.current = self.point_on_path(eased_progress),
         }
 
         progress >= 1.0
     }
 
+    /// Value at `t` along the configured path from `initial` to `target`
+    fn point_on_path(&self, t: f32) -> T {
+        let straight = self
+            .initial
+            .interpolate_in(&self.target, t, self.config.color_space);
+        let MotionPath::Arc(curvature) = self.config.path else {
+            return straight;
+        };
+        // A quadratic Bézier through a control point offset from the midpoint is the
+        // straight line plus 2t(1 - t) times that offset
+        let offset = self
+            .target
+            .sub(&self.initial)
+            .perpendicular()
+            .scale(curvature);
+        straight.add(&offset.scale(2.0 * t * (1.0 - t)))
+    }
+
+    /// Glides on with exponentially decaying velocity, finishing wherever it slows to a stop
+    fn update_decay(&mut self, decay: Decay, dt: f32) -> bool {
+        let (distance, velocity) = decay.step(self.velocity, dt);
+        self.current = self.current.add(&distance);
+        self.velocity = velocity;
+
+        if self.velocity.magnitude() < self.completion_epsilon(T::epsilon()) {
+            // The resting point comes from the physics, not the requested target
+            self.velocity = T::zero();
+            self.target = self.current;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Whether the spring has settled by the config's [`SpringCompletion`] criterion
+    fn spring_at_rest(&self, spring: &Spring) -> bool {
+        let distance = self.target.sub(&self.current).magnitude();
+        let speed = self.velocity.magnitude();
+        match self.config.spring_completion {
+            SpringCompletion::Thresholds => {
+                let epsilon = self.completion_epsilon(SPRING_REST_EPSILON);
+                distance < epsilon && speed < epsilon
+            }
+            SpringCompletion::AnyThreshold => {
+                let epsilon = self.completion_epsilon(SPRING_REST_EPSILON);
+                // Slow only counts while closing in, not when it's about to set off: at the
+                // start, or at the far end of a swing
+                let closing_in = self
+                    .target
+                    .sub(&self.current.add(&self.velocity.scale(1e-3)))
+                    .magnitude()
+                    < distance;
+                distance < epsilon || (speed < epsilon && closing_in)
+            }
+            SpringCompletion::Energy(threshold) => spring.energy(distance, speed) < threshold,
+        }
+    }
+
+    /// This animation's speed, scaled by the app-wide speed
+    fn playback_speed(&self, global: MotionConfig) -> f32 {
+        self.config.speed * global.global_speed
+    }
+
+    /// Rest threshold for springs and decays, `default` unless the config overrides it
+    fn completion_epsilon(&self, default: f32) -> f32 {
+        self.config.completion_epsilon.unwrap_or(default)
+    }
+
     fn handle_completion(&mut self) -> bool {
         let should_continue = match self.config.loop_mode.unwrap_or(LoopMode::None) {
             LoopMode::None => {
@@ -489,36 +1445,38 @@ impl<T: Animatable> Motion<T> {
                 false
             }
             LoopMode::Infinite => {
+                self.current_loop = self.current_loop.saturating_add(1);
                 self.current = self.initial;
                 self.elapsed = Duration::default();
-                self.velocity = T::zero();
+                self.velocity = self.loop_start_velocity();
                 true
             }
             LoopMode::Times(count) => {
                 self.current_loop += 1;
                 if self.current_loop >= count {
-                    self.stop();
+                    self.halt();
                     false
                 } else {
                     self.current = self.initial;
                     self.elapsed = Duration::default();
-                    self.velocity = T::zero();
+                    self.velocity = self.loop_start_velocity();
                     true
                 }
             }
             LoopMode::Alternate => {
+                self.current_loop = self.current_loop.saturating_add(1);
                 self.reverse = !self.reverse;
                 if self.reverse {
                     std::mem::swap(&mut self.initial, &mut self.target);
                 }
                 self.elapsed = Duration::default();
-                self.velocity = T::zero();
+                self.velocity = self.loop_start_velocity();
                 true
             }
             LoopMode::AlternateTimes(count) => {
                 self.current_loop += 1;
-                if self.current_loop >= count * 2 {
-                    self.stop();
+                if self.current_loop >= count.saturating_mul(2) {
+                    self.halt();
                     false
                 } else {
                     self.reverse = !self.reverse;
@@ -526,78 +1484,344 @@ impl<T: Animatable> Motion<T> {
                         std::mem::swap(&mut self.initial, &mut self.target);
                     }
                     self.elapsed = Duration::default();
-                    self.velocity = T::zero();
+                    self.velocity = self.loop_start_velocity();
                     true
                 }
             }
-        };
-
-        if !should_continue {
-            if let Some(ref f) = self.config.on_complete {
-                if let Ok(mut guard) = f.lock() {
-                    guard();
-                }
+            LoopMode::PingPong => {
+                self.current_loop = self.current_loop.saturating_add(1);
+                self.flip_direction();
+                true
             }
+            LoopMode::PingPongTimes(count) => {
+                self.current_loop += 1;
+                // Each cycle is two legs: forward to the target and back to the start
+                if self.current_loop >= count.saturating_mul(2) {
+                    self.halt();
+                    false
+                } else {
+                    self.flip_direction();
+                    true
+                }
+            }
+        };
+
+        if should_continue {
+            // Every further iteration holds at its start for the repeat delay
+            self.repeating = true;
+            self.delay_elapsed = Duration::default();
+            if let Some(mut guard) = self.config.on_loop.as_ref().and_then(|f| f.lock().ok()) {
+                guard(self.current_loop);
+            }
+            self.publish(AnimationEvent::Looped(self.current_loop));
+        } else {
+            self.notify_complete();
         }
 
         should_continue
     }
 
-    fn get_value(&self) -> T {
-        self.current
+    /// Delay before the current iteration: `delay` for the first, `repeat_delay` after a loop
+    fn current_delay(&self) -> Duration {
+        if self.repeating {
+            self.config.repeat_delay
+        } else {
+            self.config.delay
+        }
     }
 
-    fn update_keyframes(&mut self, dt: f32) -> bool {
-        if let Some(animation) = &self.keyframe_animation {
-            let progress =
-                (self.elapsed.as_secs_f32() / animation.duration.as_secs_f32()).clamp(0.0, 1.0);
-
-            // Find the current keyframe pair
-            let (start, end) = animation
-                .keyframes
-                .windows(2)
-                .find(|w| progress >= w[0].offset && progress <= w[1].offset)
-                .map(|w| (&w[0], &w[1]))
-                .unwrap_or_else(|| {
-                    // Handle edge cases
-                    if progress <= animation.keyframes[0].offset {
-                        let first = &animation.keyframes[0];
-                        (first, first)
-                    } else {
-                        let last = animation
-                            .keyframes
-                            .last()
-                            .expect("Keyframe animation must contain at least one keyframe");
-                        (last, last)
-                    }
-                });
+    /// Keeps the value of every animation within `bounds`, or lets it move freely with `None`
+    ///
+    /// Bounds stay with the motion until replaced and shape every frame an animation moves
+    /// the value; see [`Bounds`] for how they interact with spring overshoot.
+    pub fn set_bounds(&mut self, bounds: Option<Bounds<T>>) {
+        self.bounds = bounds;
+    }
 
-            // Calculate local progress between keyframes
-            let local_progress = if start.offset == end.offset {
-                1.0
-            } else {
-                (progress - start.offset) / (end.offset - start.offset)
-            };
+    /// Keeps `current` within the bounds, remembering the unbounded value so the next frame
+    /// carries on from it
+    fn apply_bounds(&mut self) {
+        let bounds = self.bounds;
+        self.unbounded = bounds.map(|bounds| {
+            let unbounded = self.current;
+            self.current = bounds.apply(unbounded);
+            (self.current, unbounded)
+        });
+    }
 
-            // Apply easing if specified
-            let eased_progress = end
-                .easing
-                .map_or(local_progress, |ease| (ease)(local_progress, 0.0, 1.0, 1.0));
+    /// Holds every component at or before its target, stopping it there on contact
+    fn clamp_to_target(&mut self) {
+        let clamped = self
+            .current
+            .map_within(&self.initial, &self.target, &|value, from, to| {
+                if from <= to {
+                    value.min(to)
+                } else {
+                    value.max(to)
+                }
+            });
+        self.velocity = self
+            .velocity
+            .map_within(&clamped, &self.target, &|speed, value, to| {
+                if value == to { 0.0 } else { speed }
+            });
+        self.current = clamped;
+    }
 
-            // Interpolate between keyframes
-            self.current = start.value.interpolate(&end.value, eased_progress);
+    /// Puts back the unbounded value, unless `current` was changed since it was bounded
+    fn restore_unbounded(&mut self) {
+        let untouched = self
+            .unbounded
+            .take()
+            .filter(|(shown, _)| self.current.sub(shown).magnitude() == 0.0);
+        if let Some((_, unbounded)) = untouched {
+            self.current = unbounded;
+        }
+    }
 
-            // Update elapsed time
-            self.elapsed += Duration::from_secs_f32(dt);
+    /// Sets a callback called with the value on every frame an animation changes it
+    ///
+    /// Unlike the callbacks of [`AnimationConfig`] it belongs to the motion rather than to
+    /// one animation, so it keeps firing for every animation until it is replaced.
+    pub fn on_update(&mut self, f: impl FnMut(T) + 'static) {
+        self.on_update = Some(Rc::new(RefCell::new(f)));
+    }
 
-            // Check for completion
-            if progress >= 1.0 {
-                self.handle_completion()
-            } else {
-                true
+    /// Fires the `on_update` callback if `current` moved away from `previous`
+    fn notify_update(&self, previous: T) {
+        if self.current.sub(&previous).magnitude() == 0.0 {
+            return;
+        }
+        if let Some(f) = &self.on_update
+            && let Ok(mut f) = f.try_borrow_mut()
+        {
+            f(self.current);
+        }
+    }
+
+    /// Whether the next sequence step overlaps the running one and it has got far enough
+    fn overlapping_step_due(&self) -> bool {
+        let Some(sequence) = &self.sequence else {
+            return false;
+        };
+        match sequence.steps.get(sequence.current_step as usize + 1) {
+            Some(AnimationStep {
+                start: StepStart::AtProgress(progress),
+                ..
+            }) => self.get_progress() >= *progress,
+            _ => false,
+        }
+    }
+
+    /// Fires the config's `on_complete` callback
+    ///
+    /// Also publishes [`AnimationEvent::Completed`] and passes the final value to
+    /// `on_complete_with`, except for the steps of a sequence, which completes as a whole.
+    fn notify_complete(&mut self) {
+        if self.sequence.is_none() {
+            self.publish(AnimationEvent::Completed);
+        }
+        if let Some(ref f) = self.config.on_complete
+            && let Ok(mut guard) = f.lock()
+        {
+            guard();
+        }
+        if self.sequence.is_none() {
+            self.settle_on_complete_with();
+        }
+    }
+
+    /// Calls `f` with the final value once the running animation completes
+    ///
+    /// Unlike the `on_complete` of [`AnimationConfig`] it receives the value, typed to
+    /// the motion. It fires once, after the config's `on_complete`, or right away when
+    /// nothing is running. Stopping the animation, or starting another one in its place,
+    /// drops it without calling it, the way a replaced config drops its `on_complete`.
+    pub fn on_complete_with(&mut self, f: impl FnOnce(T) + 'static) {
+        if self.is_running() {
+            self.on_complete_with = Some(Rc::new(Cell::new(Some(Box::new(f)))));
+        } else {
+            f(self.current);
+        }
+    }
+
+    /// Hands the final value to the `on_complete_with` callback waiting on the animation
+    fn settle_on_complete_with(&mut self) {
+        if let Some(f) = self.on_complete_with.take().and_then(|f| f.take()) {
+            f(self.current);
+        }
+    }
+
+    /// Hands out a receiver for this motion's [`AnimationEvent`]s
+    pub fn events(&mut self) -> UnboundedReceiver<AnimationEvent> {
+        let (sender, receiver) = futures_channel::mpsc::unbounded();
+        self.events.push(sender);
+        receiver
+    }
+
+    /// Sends `event` to every receiver, forgetting the ones that were dropped
+    fn publish(&mut self, event: AnimationEvent) {
+        if !self.events.is_empty() {
+            self.events
+                .retain(|sender| sender.unbounded_send(event).is_ok());
+        }
+    }
+
+    /// Publishes the start of an animation, after interrupting the running one
+    fn publish_start(&mut self) {
+        self.interrupt();
+        self.publish(AnimationEvent::Started);
+    }
+
+    /// Reports that the running animation won't complete, dropping what waited on it
+    fn interrupt(&mut self) {
+        if self.is_running() {
+            self.publish(AnimationEvent::Interrupted);
+            self.on_complete_with = None;
+        }
+    }
+
+    /// Velocity a loop starts with: at rest, or for [`LoopVelocity::Seeded`] springs the
+    /// spring's `velocity` in the direction of the target
+    fn loop_start_velocity(&self) -> T {
+        let AnimationMode::Spring(spring) = self.config.mode else {
+            return T::zero();
+        };
+        let direction = self.target.sub(&self.initial);
+        let distance = direction.magnitude();
+        if self.config.loop_velocity == LoopVelocity::Seeded && distance > 0.0 {
+            direction.scale(spring.velocity / distance)
+        } else {
+            T::zero()
+        }
+    }
+
+    /// Whether a ping-pong spring has reached its target with another leg to play
+    ///
+    /// The leg turns as the spring gets there rather than once it settles, so it heads back
+    /// with the speed it arrived with. The last leg settles as usual, ending at rest.
+    fn ping_pong_turn_due(&self) -> bool {
+        let legs_left = match self.config.loop_mode {
+            Some(LoopMode::PingPong) => true,
+            Some(LoopMode::PingPongTimes(count)) => {
+                self.current_loop.saturating_add(1) < count.saturating_mul(2)
             }
+            _ => false,
+        };
+        let span = self.target.sub(&self.initial).magnitude();
+        legs_left && span >= T::epsilon() && self.current.sub(&self.initial).magnitude() >= span
+    }
+
+    /// Swaps `initial` and `target` so the next leg plays back toward where this one began.
+    /// Tweens restart their elapsed time, while springs turn as they reach the target (see
+    /// `ping_pong_turn_due`) and keep their velocity, so the motion stays continuous.
+    fn flip_direction(&mut self) {
+        std::mem::swap(&mut self.initial, &mut self.target);
+        self.elapsed = Duration::default();
+    }
+
+    fn get_value(&self) -> T {
+        let value = self.shown();
+        self.config
+            .snap_output
+            .map_or(value, |grid| value.snap(grid))
+    }
+
+    /// The value as read before snapping, reshaped by the output easing while running
+    fn shown(&self) -> T {
+        match &self.config.output_easing {
+            Some(easing) if self.is_running() => self.eased_output(easing),
+            _ => self.current,
+        }
+    }
+
+    /// Moves the value to where the output easing shows it, so the next animation, or the
+    /// value left behind by a stopped one, carries on from there without a jump
+    fn settle_shown(&mut self) {
+        self.current = self.shown();
+    }
+
+    /// The current value moved to where `easing` puts its progress towards the target
+    ///
+    /// Progress is the fraction of the distance from the start covered, above 1.0 past the
+    /// target. Scaling the offset from the target keeps its direction, so values that
+    /// stray off the straight line between start and target stay off it.
+    fn eased_output(&self, easing: &Easing) -> T {
+        let span = self.target.sub(&self.initial).magnitude();
+        let offset = self.current.sub(&self.target);
+        let distance = offset.magnitude();
+        if span < T::epsilon() || distance < T::epsilon() {
+            return self.current;
+        }
+
+        let remaining = distance / span;
+        let travelled = self.current.sub(&self.initial).magnitude();
+        // Further from the start than the target is, and not by being behind the start
+        let past_target = travelled > span && travelled > distance;
+        let progress = if past_target {
+            1.0 + remaining
         } else {
-            false
+            1.0 - remaining
+        };
+        let eased = easing.ease(progress, 0.0, 1.0, 1.0);
+        self.target
+            .add(&offset.scale((1.0 - eased) / (1.0 - progress)))
+    }
+
+    /// Where the running animation is heading, or the current value when idle
+    ///
+    /// A spring leading a moving target reports the target itself, not the point it aims at.
+    fn get_target(&self) -> T {
+        if self.is_running() {
+            self.lead.map_or(self.target, |lead| lead.target)
+        } else {
+            self.current
+        }
+    }
+
+    /// Velocity of the running animation, or of a drag that moved the value recently enough
+    /// to be handed over
+    fn get_velocity(&self) -> T {
+        if self.is_running() || self.dragging(self.now()) {
+            self.velocity
+        } else {
+            T::zero()
+        }
+    }
+
+    fn update_keyframes(&mut self, dt: f32) -> AnimationFrameStatus {
+        let Some(animation) = self.keyframe_animation.clone() else {
+            return AnimationFrameStatus::Idle;
+        };
+
+        self.elapsed += Duration::from_secs_f32(dt);
+        let duration = animation.duration.as_secs_f32();
+        let progress = if duration > 0.0 {
+            (self.elapsed.as_secs_f32() / duration).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+
+        let Some(value) = animation.value_at(progress) else {
+            // Nothing to animate through
+            self.keyframe_animation = None;
+            self.running = false;
+            return AnimationFrameStatus::CompletedThisFrame;
+        };
+        let previous = self.current;
+        self.current = value;
+        self.apply_bounds();
+        self.notify_update(previous);
+
+        if progress < 1.0 {
+            return AnimationFrameStatus::Active;
+        }
+
+        if self.handle_completion() {
+            AnimationFrameStatus::LoopedThisFrame
+        } else {
+            self.keyframe_animation = None;
+            AnimationFrameStatus::CompletedThisFrame
         }
     }
 }
@@ -606,14 +1830,150 @@ impl<T: Animatable> Motion<T> {
 pub trait AnimationManager<T: Animatable>: Clone + Copy {
     fn new(initial: T) -> Self;
     fn animate_to(&mut self, target: T, config: AnimationConfig);
+    /// Same as [`AnimationManager::animate_to`], but leaves a running animation that already
+    /// heads to `target` alone, returning `false` when it did so; the skipped call's config
+    /// is dropped
+    fn try_animate_to(&mut self, target: T, config: AnimationConfig) -> bool;
+    /// Moves the target in place without restarting, for following a target on every event
+    fn retarget(&mut self, target: T);
+    /// Animates relative to the current value, reading and retargeting in one step
+    fn animate_by(&mut self, delta: T, config: AnimationConfig);
+    /// Starts an animation that keeps `initial_velocity` instead of starting from rest,
+    /// so a gesture or an interrupted animation can hand off its momentum
+    fn animate_to_with_velocity(&mut self, target: T, config: AnimationConfig, initial_velocity: T);
+    /// Flings the value with `velocity`; with [`AnimationMode::Decay`] it glides to rest
+    /// wherever its momentum runs out, for inertial scrolling and throw gestures
+    fn animate_decay(&mut self, velocity: T, config: AnimationConfig);
     fn animate_sequence(&mut self, sequence: AnimationSequence<T>);
     fn animate_keyframes(&mut self, animation: KeyframeAnimation<T>);
+    /// Steps the animation by `dt` seconds, returning whether it still needs frames
+    ///
+    /// Shorthand for [`AnimationManager::update_status`] with the status collapsed to a `bool`.
     fn update(&mut self, dt: f32) -> bool;
+    /// Steps the animation by `dt` seconds, reporting whether it was delayed, moved,
+    /// looped or finished on this frame
+    fn update_status(&mut self, dt: f32) -> AnimationFrameStatus;
     fn get_value(&self) -> T;
+    /// Whether the current value is within `tolerance` of `value`, the distance measured
+    /// with [`Animatable::magnitude`]
+    ///
+    /// Reads the value like [`AnimationManager::get_value`], so the caller is subscribed to it.
+    fn is_at(&self, value: T, tolerance: f32) -> bool {
+        self.get_value().sub(&value).magnitude() < tolerance
+    }
+    /// Reads the current value without subscribing the calling scope to it
+    ///
+    /// For imperative loops, such as drawing to a canvas from `use_future`, that poll the
+    /// value every frame and shouldn't re-render the component as it changes.
+    fn peek_value(&self) -> T;
+    /// Returns where the animation is heading: the target of the running animation, or of
+    /// the current step of a sequence, and the value it rests at when idle
+    fn get_target(&self) -> T;
+    /// Returns a reactive read handle to the current value, for deriving other state from it
+    ///
+    /// Reading the handle subscribes to the animation like [`AnimationManager::get_value`],
+    /// so memos and effects built on it update every frame. It reads the value as animated,
+    /// before any [`AnimationConfig::with_output_easing`] or
+    /// [`AnimationConfig::with_snap_output`] reshaping.
+    ///
+    /// ```no_run
+    /// use dioxus_motion::prelude::*;
+    /// use dioxus::prelude::*;
+    ///
+    /// fn app() -> Element {
+    ///     let x = use_motion(0.0f32);
+    ///     let x_value = x.value_signal();
+    ///     let past_halfway = use_memo(move || *x_value.read() > 50.0);
+    ///
+    ///     rsx! { div { "Past halfway: {past_halfway}" } }
+    /// }
+    /// ```
+    fn value_signal(&self) -> MappedSignal<T>;
+    /// Returns the live velocity of the animation, or the velocity estimated from recent
+    /// `set_value` calls, or zero when nothing is moving the value
+    fn get_velocity(&self) -> T;
     fn is_running(&self) -> bool;
+    /// True while a running animation is holding for its delay rather than moving
+    fn is_delayed(&self) -> bool;
     fn reset(&mut self);
     fn stop(&mut self);
+    /// Sets the value directly, stopping any animation, for following a drag frame by frame
+    ///
+    /// The velocity of successive calls is tracked, so a spring started on release
+    /// continues the drag's momentum.
+    fn set_value(&mut self, value: T);
+    /// Captures the state of the animation, to put back later with [`AnimationManager::restore`]
+    fn snapshot(&self) -> AnimationSnapshot<T>;
+    /// Puts back a state captured with [`AnimationManager::snapshot`]
+    ///
+    /// A snapshot of a running animation carries on from where it was taken, using the
+    /// config the motion has now.
+    fn restore(&mut self, snapshot: AnimationSnapshot<T>);
+    /// Jumps to the target, fires `on_complete` and stops, ending any loops
+    fn finish(&mut self);
     fn delay(&mut self, duration: Duration);
+    /// Number of loops completed so far in the current animation
+    fn current_loop(&self) -> u32;
+    /// Normalized 0.0 to 1.0 progress: un-eased time for tweens, distance covered for springs
+    fn get_progress(&self) -> f32;
+    /// Estimated time until the animation completes, or `None` when idle or looping forever
+    fn time_remaining(&self) -> Option<Duration>;
+    /// Plays the animation back towards its starting value without losing momentum
+    fn reverse(&mut self);
+    /// Scrubs to a 0.0 to 1.0 progress between the start and target instead of playing over time
+    fn seek(&mut self, progress: f32);
+    /// Freezes a running animation without discarding its progress
+    fn pause(&mut self);
+    /// Continues a paused animation from where it was paused
+    fn resume(&mut self);
+    /// Resolves once the animation stops running, for sequencing steps in async handlers
+    ///
+    /// A paused animation keeps this pending until it is resumed and finishes, and an
+    /// infinitely looping one never resolves unless it is stopped.
+    fn wait_until_done(&self) -> impl Future<Output = ()>;
+    /// A stream of the animation's lifecycle events, for code outside Dioxus that wants to
+    /// follow it without polling
+    ///
+    /// Each call hands out a new receiver that gets every event from then on. Dropped
+    /// receivers are forgotten the next time an event is published.
+    fn events(&mut self) -> UnboundedReceiver<AnimationEvent>;
+    /// Sets a callback called with the value on every frame an animation changes it,
+    /// for every animation until it is replaced
+    ///
+    /// Register it once rather than on every render:
+    ///
+    /// ```no_run
+    /// use dioxus_motion::prelude::*;
+    /// use dioxus::prelude::*;
+    ///
+    /// fn app() -> Element {
+    ///     let mut x = use_motion(0.0f32);
+    ///     use_hook(move || x.on_update(|value| println!("{value}")));
+    ///
+    ///     rsx! { div { onclick: move |_| x.animate_to(100.0, AnimationConfig::default()) } }
+    /// }
+    /// ```
+    fn on_update(&mut self, f: impl FnMut(T) + 'static);
+    /// Keeps the value of every animation within `bounds`, or lets it move freely with `None`
+    ///
+    /// ```no_run
+    /// use dioxus_motion::prelude::*;
+    /// use dioxus::prelude::*;
+    ///
+    /// fn app() -> Element {
+    ///     let mut x = use_motion(0.0f32);
+    ///     use_hook(move || x.set_bounds(Some(Bounds::new(0.0, 100.0).with_rubber_band(0.55))));
+    ///
+    ///     rsx! { div { onclick: move |_| x.animate_to(150.0, AnimationConfig::default()) } }
+    /// }
+    /// ```
+    fn set_bounds(&mut self, bounds: Option<Bounds<T>>);
+    /// Calls `f` with the final value once the running animation completes, or right away
+    /// when nothing is running
+    ///
+    /// Register it after starting the animation it waits for. Stopping that animation or
+    /// starting another one in its place drops `f` without calling it.
+    fn on_complete_with(&mut self, f: impl FnOnce(T) + 'static);
 }
 
 impl<T: Animatable> AnimationManager<T> for Signal<Motion<T>> {
@@ -622,33 +1982,84 @@ impl<T: Animatable> AnimationManager<T> for Signal<Motion<T>> {
     }
 
     fn animate_to(&mut self, target: T, config: AnimationConfig) {
-        self.write().animate_to(target, config);
+        ticker::drive(*self, |motion| motion.animate_to(target, config));
+    }
+
+    fn try_animate_to(&mut self, target: T, config: AnimationConfig) -> bool {
+        let mut started = false;
+        ticker::drive(*self, |motion| {
+            started = motion.try_animate_to(target, config);
+        });
+        started
+    }
+
+    fn animate_by(&mut self, delta: T, config: AnimationConfig) {
+        ticker::drive(*self, |motion| motion.animate_by(delta, config));
+    }
+
+    fn retarget(&mut self, target: T) {
+        ticker::drive(*self, |motion| motion.retarget(target));
+    }
+
+    fn animate_to_with_velocity(
+        &mut self,
+        target: T,
+        config: AnimationConfig,
+        initial_velocity: T,
+    ) {
+        ticker::drive(*self, |motion| {
+            motion.animate_to_with_velocity(target, config, initial_velocity)
+        });
+    }
+
+    fn animate_decay(&mut self, velocity: T, config: AnimationConfig) {
+        ticker::drive(*self, |motion| motion.animate_decay(velocity, config));
     }
 
     fn animate_sequence(&mut self, sequence: AnimationSequence<T>) {
-        if let Some(first_step) = sequence.steps.first() {
-            let mut state = self.write();
-            state.animate_to(first_step.target, (*first_step.config).clone());
-            state.sequence = Some(sequence.into());
-        }
+        ticker::drive(*self, |motion| motion.animate_sequence(sequence));
     }
 
     fn animate_keyframes(&mut self, animation: KeyframeAnimation<T>) {
-        self.write().animate_keyframes(animation);
+        ticker::drive(*self, |motion| motion.animate_keyframes(animation));
     }
 
     fn update(&mut self, dt: f32) -> bool {
         self.write().update(dt)
     }
 
+    fn update_status(&mut self, dt: f32) -> AnimationFrameStatus {
+        self.write().update_status(dt)
+    }
+
     fn get_value(&self) -> T {
         self.read().get_value()
     }
 
+    fn peek_value(&self) -> T {
+        self.peek().get_value()
+    }
+
+    fn value_signal(&self) -> MappedSignal<T> {
+        self.map(|motion| &motion.current)
+    }
+
+    fn get_target(&self) -> T {
+        self.read().get_target()
+    }
+
+    fn get_velocity(&self) -> T {
+        self.read().get_velocity()
+    }
+
     fn is_running(&self) -> bool {
         self.read().is_running()
     }
 
+    fn is_delayed(&self) -> bool {
+        self.read().is_delayed()
+    }
+
     fn reset(&mut self) {
         self.write().reset();
     }
@@ -657,20 +2068,95 @@ impl<T: Animatable> AnimationManager<T> for Signal<Motion<T>> {
         self.write().stop();
     }
 
+    fn set_value(&mut self, value: T) {
+        self.write().set_value(value);
+    }
+
+    fn snapshot(&self) -> AnimationSnapshot<T> {
+        self.read().snapshot()
+    }
+
+    fn restore(&mut self, snapshot: AnimationSnapshot<T>) {
+        ticker::drive(*self, |motion| motion.restore(snapshot));
+    }
+
+    fn finish(&mut self) {
+        self.write().finish();
+    }
+
     fn delay(&mut self, duration: Duration) {
         let mut state = self.write();
         let mut config = (*state.config).clone();
         config.delay = duration;
         state.config = Arc::new(config);
     }
+
+    fn current_loop(&self) -> u32 {
+        self.read().current_loop()
+    }
+
+    fn get_progress(&self) -> f32 {
+        self.read().get_progress()
+    }
+
+    fn time_remaining(&self) -> Option<Duration> {
+        self.read().time_remaining()
+    }
+
+    fn reverse(&mut self) {
+        ticker::drive(*self, |motion| motion.reverse());
+    }
+
+    fn seek(&mut self, progress: f32) {
+        self.write().seek(progress);
+    }
+
+    fn pause(&mut self) {
+        self.write().pause();
+    }
+
+    fn resume(&mut self) {
+        ticker::drive(*self, |motion| motion.resume());
+    }
+
+    fn wait_until_done(&self) -> impl Future<Output = ()> {
+        let motion = *self;
+        async move {
+            // Check once per ticker frame; peeking keeps the caller from subscribing
+            while motion.try_peek().is_ok_and(|state| state.is_running()) {
+                ticker::next_frame().await;
+            }
+        }
+    }
+
+    fn events(&mut self) -> UnboundedReceiver<AnimationEvent> {
+        self.write().events()
+    }
+
+    fn on_update(&mut self, f: impl FnMut(T) + 'static) {
+        self.write().on_update(f);
+    }
+
+    fn set_bounds(&mut self, bounds: Option<Bounds<T>>) {
+        self.write().set_bounds(bounds);
+    }
+
+    fn on_complete_with(&mut self, f: impl FnOnce(T) + 'static) {
+        // Called outside the borrow when idle, so `f` can read the motion
+        if self.peek().is_running() {
+            self.write().on_complete_with(f);
+        } else {
+            f(self.peek_value());
+        }
+    }
 }
 
 /// Creates an animation manager that continuously updates a motion state.
 ///
-/// This function initializes a motion state with the provided initial value and spawns an asynchronous loop
-/// that updates the animation state based on the elapsed time between frames. When the animation is running,
-/// it updates the state using the calculated time delta and dynamically adjusts the update interval to optimize CPU usage;
-/// when the animation is inactive, it waits longer before polling again.
+/// This function initializes a motion state with the provided initial value. Whenever an animation
+/// starts, the motion joins a shared ticker that steps every running motion with the same time delta
+/// each frame, so elements animated together stay in sync. Motions leave the ticker when they settle,
+/// and the ticker stops entirely once nothing is animating.
 ///
 /// # Example
 ///
@@ -696,109 +2182,3138 @@ impl<T: Animatable> AnimationManager<T> for Signal<Motion<T>> {
 /// }
 /// ```
 pub fn use_motion<T: Animatable>(initial: T) -> impl AnimationManager<T> {
-    let mut state = use_signal(|| Motion::new(initial));
+    use_signal(|| Motion::new(initial))
+}
 
-    #[cfg(feature = "web")]
-    let idle_poll_rate = Duration::from_millis(100);
+/// Creates an animation manager that is only stepped by the caller, for apps that run
+/// their own frame loop
+///
+/// Unlike [`use_motion`], starting an animation doesn't hand the motion to the shared
+/// ticker, so nothing moves until [`AnimationManager::update`] is called. Call it once per
+/// frame with the frame's time step for as long as `is_running` is true. Everything else,
+/// including `get_value`, `is_running` and the callbacks, works as usual.
+///
+/// `wait_until_done` still checks once per display frame, so it resolves on the first
+/// frame after the caller's loop finishes the animation.
+///
+/// # Example
+///
+/// ```no_run
+/// use dioxus_motion::prelude::*;
+/// use dioxus::prelude::*;
+///
+/// fn app() -> Element {
+///     let mut value = use_motion_manual(0.0f32);
+///
+///     // The game loop steps the value at a fixed rate alongside everything else
+///     use_future(move || async move {
+///         const STEP: f32 = 1.0 / 60.0;
+///         value.animate_to(100.0, AnimationConfig::new(AnimationMode::Spring(Spring::default())));
+///         while value.is_running() {
+///             Time::delay(Duration::from_secs_f32(STEP)).await;
+///             value.update(STEP);
+///         }
+///     });
+///
+///     rsx! {
+///         div {
+///             style: "transform: translateY({value.get_value()}px)",
+///             "Animated content"
+///         }
+///     }
+/// }
+/// ```
+pub fn use_motion_manual<T: Animatable>(initial: T) -> impl AnimationManager<T> {
+    use_signal(|| Motion {
+        manual: true,
+        ..Motion::new(initial)
+    })
+}
 
-    #[cfg(not(feature = "web"))]
-    let idle_poll_rate = Duration::from_millis(33);
+/// Creates an animation manager that follows a target signal
+///
+/// The value starts at the target's current value, and every time `target` changes it
+/// animates to the new value with `config` (the one passed on the first render). A change
+/// that arrives mid-flight retargets the running animation from where it is instead of
+/// restarting it, and springs keep their momentum, so rapidly changing targets stay smooth.
+///
+/// # Example
+///
+/// ```no_run
+/// use dioxus_motion::prelude::*;
+/// use dioxus::prelude::*;
+///
+/// fn app() -> Element {
+///     let mut pointer_x = use_signal(|| 0.0f32);
+///     let x = use_motion_to(
+///         pointer_x,
+///         AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+///     );
+///
+///     rsx! {
+///         div {
+///             onmousemove: move |event| pointer_x.set(event.client_coordinates().x as f32),
+///             div { style: "transform: translateX({x.get_value()}px)" }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_motion_to<T: Animatable>(
+    target: Signal<T>,
+    config: AnimationConfig,
+) -> impl AnimationManager<T> {
+    let mut motion = use_signal(|| Motion::new(*target.peek()));
 
     use_effect(move || {
-        // This executes after rendering is complete
-        spawn(async move {
-            let mut last_frame = Time::now();
-            let mut _running_frames = 0u32;
-
-            loop {
-                let now = Time::now();
-                let dt = (now.duration_since(last_frame).as_secs_f32()).min(0.1);
-                last_frame = now;
-
-                // Only check if running first, then write to the signal
-                if state.peek().is_running() {
-                    _running_frames += 1;
-                    state.write().update(dt);
-
-                    #[cfg(feature = "web")]
-                    // Adaptive frame rate
-                    let delay = match dt {
-                        x if x < 0.008 => Duration::from_millis(8),  // ~120fps
-                        x if x < 0.016 => Duration::from_millis(16), // ~60fps
-                        _ => Duration::from_millis(32),              // ~30fps
-                    };
-
-                    #[cfg(not(feature = "web"))]
-                    let delay = match _running_frames {
-                        // Higher frame rate for the first ~200 frames for smooth starts
-                        0..=200 => Duration::from_micros(8333), // ~120fps
-                        _ => match dt {
-                            x if x < 0.005 => Duration::from_millis(8),  // ~120fps
-                            x if x < 0.011 => Duration::from_millis(16), // ~60fps
-                            _ => Duration::from_millis(33),              // ~30fps
-                        },
-                    };
-
-                    Time::delay(delay).await;
-                } else {
-                    _running_frames = 0;
-                    Time::delay(idle_poll_rate).await;
-                }
-            }
-        });
+        let target = target();
+        // Peek so the effect doesn't rerun on every animation frame
+        let unchanged = motion.peek().target.sub(&target).magnitude() <= T::epsilon();
+        if !unchanged {
+            motion.animate_to(target, config.clone());
+        }
     });
 
-    state
+    motion
 }
 
-// Reuse allocations for common operations
-thread_local! {
-    static TRANSFORM_BUFFER: RefCell<Vec<Transform>> = RefCell::new(Vec::with_capacity(32));
-    static SPRING_BUFFER: RefCell<Vec<SpringState>> = RefCell::new(Vec::with_capacity(16));
+/// Read handle over a value derived from two animation managers, created by [`combine`]
+///
+/// Nothing is cached: every read applies the combining function to the managers' current
+/// values, so it is always in step with both of them.
+#[derive(Clone, Copy)]
+pub struct Combined<A, B, F, T, U> {
+    a: A,
+    b: B,
+    f: F,
+    values: PhantomData<fn() -> (T, U)>,
 }
 
-/// Represents a single keyframe in an animation
-/// Represents a single keyframe in an animation
-#[derive(Clone)]
-pub struct Keyframe<T: Animatable> {
-    /// The target value at this keyframe
-    value: T,
-    /// Timing as a percentage (0.0 to 1.0)
-    offset: f32,
-    /// Optional easing function for this specific keyframe
-    easing: Option<fn(f32, f32, f32, f32) -> f32>,
-}
-/// Keyframe animation configuration
-#[derive(Clone)]
-pub struct KeyframeAnimation<T: Animatable> {
-    keyframes: Vec<Keyframe<T>>,
-    duration: Duration,
-}
+impl<A, B, F, T, U, O> Combined<A, B, F, T, U>
+where
+    A: AnimationManager<T>,
+    B: AnimationManager<U>,
+    F: Fn(T, U) -> O,
+    T: Animatable,
+    U: Animatable,
+{
+    /// Combined current value, subscribing the caller to both managers like
+    /// [`AnimationManager::get_value`] does
+    pub fn get_value(&self) -> O {
+        (self.f)(self.a.get_value(), self.b.get_value())
+    }
 
-impl<T: Animatable> KeyframeAnimation<T> {
-    pub fn new(duration: Duration) -> Self {
-        Self {
-            keyframes: Vec::new(),
-            duration,
-        }
+    /// Combined current value without subscribing to either manager
+    pub fn peek_value(&self) -> O {
+        (self.f)(self.a.peek_value(), self.b.peek_value())
     }
 
-    pub fn add_keyframe(
-        mut self,
-        value: T,
-        offset: f32,
-        easing: Option<fn(f32, f32, f32, f32) -> f32>,
-    ) -> Self {
-        self.keyframes.push(Keyframe {
-            value,
-            offset: offset.clamp(0.0, 1.0),
-            easing,
-        });
-        self.keyframes.sort_by(|a, b| {
-            a.offset
-                .partial_cmp(&b.offset)
-                .expect("Failed to compare keyframe offsets - possible NaN value")
-        });
-        self
+    /// True while either manager is still animating
+    pub fn is_running(&self) -> bool {
+        self.a.is_running() || self.b.is_running()
+    }
+}
+
+/// Derives one value from two animation managers, such as a base position plus a shake
+///
+/// The result is a `Copy` read handle rather than a signal. Reading it with `get_value`
+/// during render subscribes the component to both managers, so it rerenders whenever
+/// either one moves and sees `f` applied to their values from the same frame. Since `f` runs
+/// on every read, keep it cheap, or wrap the read in `use_memo` to share one result. This
+/// isn't a hook and can be called anywhere, including conditionally.
+///
+/// # Example
+///
+/// ```no_run
+/// use dioxus_motion::prelude::*;
+/// use dioxus::prelude::*;
+///
+/// fn app() -> Element {
+///     let drift = use_motion(0.0f32);
+///     let mut shake = use_motion(0.0f32);
+///     let x = combine(drift, shake, |base: f32, offset: f32| base + offset);
+///
+///     let bump = move |_| {
+///         shake.animate_to_with_velocity(
+///             0.0,
+///             AnimationConfig::new(AnimationMode::Spring(Spring::wobbly())),
+///             400.0,
+///         );
+///     };
+///
+///     rsx! {
+///         div { onclick: bump, style: "transform: translateX({x.get_value()}px)" }
+///     }
+/// }
+/// ```
+pub fn combine<A, B, F, T, U, O>(a: A, b: B, f: F) -> Combined<A, B, F, T, U>
+where
+    A: AnimationManager<T>,
+    B: AnimationManager<U>,
+    F: Fn(T, U) -> O,
+    T: Animatable,
+    U: Animatable,
+{
+    Combined {
+        a,
+        b,
+        f,
+        values: PhantomData,
+    }
+}
+
+/// A fixed set of motion values that always advance together
+///
+/// Every member is stepped by the shared ticker in the same pass with the same time
+/// delta, so values that describe one element (say x, y and opacity) never drift apart.
+#[derive(Clone, Copy)]
+pub struct MotionGroup<T: Animatable, const N: usize> {
+    members: [Signal<Motion<T>>; N],
+}
+
+impl<T: Animatable, const N: usize> MotionGroup<T, N> {
+    /// Returns every member as its own animation manager, in the order they were created
+    pub fn members(&self) -> [impl AnimationManager<T> + use<T, N>; N] {
+        self.members
+    }
+
+    /// Returns the member at `index`, if there is one
+    pub fn get(&self, index: usize) -> Option<impl AnimationManager<T> + use<T, N>> {
+        self.members.get(index).copied()
+    }
+
+    /// Current value of every member
+    pub fn values(&self) -> [T; N] {
+        self.members.map(|member| member.get_value())
+    }
+
+    /// Where every member is heading, see [`AnimationManager::get_target`]
+    pub fn targets(&self) -> [T; N] {
+        self.members.map(|member| member.get_target())
+    }
+
+    /// True while any member is still animating
+    pub fn is_running(&self) -> bool {
+        self.members.iter().any(|member| member.is_running())
+    }
+
+    /// Stops every member in place
+    pub fn stop(&mut self) {
+        for member in self.members.iter_mut() {
+            member.stop();
+        }
+    }
+
+    /// Animates each member to its target, starting each one `stagger` after the previous
+    ///
+    /// Member `i` waits `config.delay + i * stagger` before it begins, using the same delay
+    /// mechanism as [`AnimationConfig::with_delay`]. The config is shared, so an
+    /// `on_complete` callback fires once per member.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use dioxus_motion::prelude::*;
+    /// use dioxus::prelude::*;
+    ///
+    /// fn app() -> Element {
+    ///     let mut bars = use_motion_group([0.0f32; 5]);
+    ///
+    ///     // Kick off the whole list once; the shared ticker drives every bar from here
+    ///     use_effect(move || {
+    ///         bars.animate_to_staggered(
+    ///             [100.0; 5],
+    ///             AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+    ///             Duration::from_millis(50),
+    ///         );
+    ///     });
+    ///
+    ///     rsx! {
+    ///         for height in bars.values() {
+    ///             div { style: "height: {height}px" }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn animate_to_staggered(
+        &mut self,
+        targets: [T; N],
+        config: AnimationConfig,
+        stagger: Duration,
+    ) {
+        for (index, (member, target)) in self.members.iter_mut().zip(targets).enumerate() {
+            let offset = stagger.saturating_mul(u32::try_from(index).unwrap_or(u32::MAX));
+            let delay = config.delay.saturating_add(offset);
+            member.animate_to(target, config.clone().with_delay(delay));
+        }
+    }
+}
+
+/// Creates a group of motion values that are updated in lockstep
+///
+/// # Example
+///
+/// ```no_run
+/// use dioxus_motion::prelude::*;
+/// use dioxus::prelude::*;
+///
+/// fn app() -> Element {
+///     let group = use_motion_group([0.0f32, 0.0, 1.0]);
+///     let [mut x, mut y, _opacity] = group.members();
+///
+///     let config = AnimationConfig::new(AnimationMode::Spring(Spring::default()));
+///     x.animate_to(100.0, config.clone());
+///     y.animate_to(50.0, config);
+///
+///     rsx! {
+///         div { "{x.get_value()}, {y.get_value()}" }
+///     }
+/// }
+/// ```
+pub fn use_motion_group<T: Animatable, const N: usize>(initial: [T; N]) -> MotionGroup<T, N> {
+    use_hook(|| MotionGroup {
+        members: initial.map(|value| Signal::new(Motion::new(value))),
+    })
+}
+
+/// Per-part animation configs for a [`TransformMotion`]
+///
+/// Starts from one config shared by every part, the common case, and lets individual
+/// parts opt into their own, such as a snappy spring for scale and a slow one for
+/// translation. Any `AnimationConfig` converts into one with `.into()`.
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::prelude::*;
+/// let config = TransformAnimationConfig::new(AnimationConfig::new(AnimationMode::Spring(
+///     Spring::slow(),
+/// )))
+/// .with_scale(AnimationConfig::new(AnimationMode::Spring(Spring::stiff())));
+/// ```
+#[derive(Clone)]
+pub struct TransformAnimationConfig {
+    /// Config for `x` and `y`
+    pub translate: AnimationConfig,
+    /// Config for `scale`
+    pub scale: AnimationConfig,
+    /// Config for `rotation`
+    pub rotation: AnimationConfig,
+    /// Config for `skew_x` and `skew_y`
+    pub skew: AnimationConfig,
+    /// Config for `rotate_x`, `rotate_y` and `rotate_z`
+    pub rotation_3d: AnimationConfig,
+}
+
+impl TransformAnimationConfig {
+    /// Uses `config` for every part of the transform
+    pub fn new(config: AnimationConfig) -> Self {
+        Self {
+            translate: config.clone(),
+            scale: config.clone(),
+            rotation: config.clone(),
+            skew: config.clone(),
+            rotation_3d: config,
+        }
+    }
+
+    /// Sets the config for `x` and `y`
+    pub fn with_translate(mut self, config: AnimationConfig) -> Self {
+        self.translate = config;
+        self
+    }
+
+    /// Sets the config for `scale`
+    pub fn with_scale(mut self, config: AnimationConfig) -> Self {
+        self.scale = config;
+        self
+    }
+
+    /// Sets the config for `rotation`
+    pub fn with_rotation(mut self, config: AnimationConfig) -> Self {
+        self.rotation = config;
+        self
+    }
+
+    /// Sets the config for `skew_x` and `skew_y`
+    pub fn with_skew(mut self, config: AnimationConfig) -> Self {
+        self.skew = config;
+        self
+    }
+
+    /// Sets the config for `rotate_x`, `rotate_y` and `rotate_z`
+    pub fn with_rotation_3d(mut self, config: AnimationConfig) -> Self {
+        self.rotation_3d = config;
+        self
+    }
+}
+
+impl From<AnimationConfig> for TransformAnimationConfig {
+    fn from(config: AnimationConfig) -> Self {
+        Self::new(config)
+    }
+}
+
+/// A [`Transform`] split into the parts a [`TransformMotion`] animates separately
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct TransformParts {
+    translate: Vector2D,
+    scale: f32,
+    rotation: f32,
+    skew: Vector2D,
+    rotation_3d: [f32; 3],
+}
+
+impl From<Transform> for TransformParts {
+    fn from(transform: Transform) -> Self {
+        Self {
+            translate: Vector2D::new(transform.x, transform.y),
+            scale: transform.scale,
+            rotation: transform.rotation,
+            skew: Vector2D::new(transform.skew_x, transform.skew_y),
+            rotation_3d: [transform.rotate_x, transform.rotate_y, transform.rotate_z],
+        }
+    }
+}
+
+impl From<TransformParts> for Transform {
+    fn from(parts: TransformParts) -> Self {
+        let [rotate_x, rotate_y, rotate_z] = parts.rotation_3d;
+        Transform::new(
+            parts.translate.x,
+            parts.translate.y,
+            parts.scale,
+            parts.rotation,
+        )
+        .with_skew(parts.skew.x, parts.skew.y)
+        .with_rotation_3d(rotate_x, rotate_y, rotate_z)
+    }
+}
+
+/// A [`Transform`] whose parts animate independently, each with its own config
+///
+/// Translation, scale, rotation, skew and 3D rotation are separate motions stepped by the
+/// shared ticker in the same pass, so with a [`TransformAnimationConfig`] each can settle
+/// in its own time. Given a single `AnimationConfig` it behaves like a `use_motion`
+/// transform. Each part runs its own animation, so callbacks in a config fire once per
+/// part, and rotation animates as a plain number rather than taking the shortest way round.
+#[derive(Clone, Copy)]
+pub struct TransformMotion {
+    translate: Signal<Motion<Vector2D>>,
+    scale: Signal<Motion<f32>>,
+    rotation: Signal<Motion<f32>>,
+    skew: Signal<Motion<Vector2D>>,
+    rotation_3d: Signal<Motion<[f32; 3]>>,
+}
+
+impl TransformMotion {
+    /// Animates every part towards `target` with its config
+    pub fn animate_to(&mut self, target: Transform, config: impl Into<TransformAnimationConfig>) {
+        let config = config.into();
+        let target = TransformParts::from(target);
+        self.translate
+            .animate_to(target.translate, config.translate);
+        self.scale.animate_to(target.scale, config.scale);
+        self.rotation.animate_to(target.rotation, config.rotation);
+        self.skew.animate_to(target.skew, config.skew);
+        self.rotation_3d
+            .animate_to(target.rotation_3d, config.rotation_3d);
+    }
+
+    /// Current transform, put together from every part
+    pub fn get_value(&self) -> Transform {
+        TransformParts {
+            translate: self.translate.get_value(),
+            scale: self.scale.get_value(),
+            rotation: self.rotation.get_value(),
+            skew: self.skew.get_value(),
+            rotation_3d: self.rotation_3d.get_value(),
+        }
+        .into()
+    }
+
+    /// Where every part is heading, see [`AnimationManager::get_target`]
+    pub fn get_target(&self) -> Transform {
+        TransformParts {
+            translate: self.translate.get_target(),
+            scale: self.scale.get_target(),
+            rotation: self.rotation.get_target(),
+            skew: self.skew.get_target(),
+            rotation_3d: self.rotation_3d.get_target(),
+        }
+        .into()
+    }
+
+    /// True while any part is still animating
+    pub fn is_running(&self) -> bool {
+        self.translate.is_running()
+            || self.scale.is_running()
+            || self.rotation.is_running()
+            || self.skew.is_running()
+            || self.rotation_3d.is_running()
+    }
+
+    /// Stops every part in place
+    pub fn stop(&mut self) {
+        self.translate.stop();
+        self.scale.stop();
+        self.rotation.stop();
+        self.skew.stop();
+        self.rotation_3d.stop();
+    }
+}
+
+/// Creates a transform whose parts can animate with different configs
+///
+/// # Example
+///
+/// ```no_run
+/// use dioxus_motion::prelude::*;
+/// use dioxus::prelude::*;
+///
+/// fn app() -> Element {
+///     let mut card = use_transform_motion(Transform::identity());
+///
+///     let lift = move |_| {
+///         let slow = AnimationConfig::new(AnimationMode::Spring(Spring::slow()));
+///         let snappy = AnimationConfig::new(AnimationMode::Spring(Spring::stiff()));
+///         card.animate_to(
+///             Transform::translate(0.0, -20.0).with_scale(1.1),
+///             TransformAnimationConfig::new(slow).with_scale(snappy),
+///         );
+///     };
+///
+///     rsx! {
+///         div { onclick: lift, style: "transform: {card.get_value().to_css_transform()}" }
+///     }
+/// }
+/// ```
+pub fn use_transform_motion(initial: Transform) -> TransformMotion {
+    use_hook(|| {
+        let parts = TransformParts::from(initial);
+        TransformMotion {
+            translate: Signal::new(Motion::new(parts.translate)),
+            scale: Signal::new(Motion::new(parts.scale)),
+            rotation: Signal::new(Motion::new(parts.rotation)),
+            skew: Signal::new(Motion::new(parts.skew)),
+            rotation_3d: Signal::new(Motion::new(parts.rotation_3d)),
+        }
+    })
+}
+
+/// Elasticity of a scroll offset past its bounds, the value iOS scroll views use
+const SCROLL_ELASTICITY: f32 = 0.55;
+
+/// Drag and snap state behind a [`ScrollMotion`]
+struct ScrollState {
+    snap_points: Vec<f32>,
+    bounds: (f32, f32),
+    dragged: Option<f32>, // Where the drag has taken the offset before rubber banding
+}
+
+impl ScrollState {
+    fn overscroll(&self) -> Bounds<f32> {
+        Bounds::new(self.bounds.0, self.bounds.1).with_rubber_band(SCROLL_ELASTICITY)
+    }
+
+    /// Moves the offset by a scroll delta, resisting more the further it goes past the bounds
+    fn scroll_by(&mut self, motion: &mut Motion<f32>, delta: f32, now: Instant) {
+        // A new drag picks the offset up wherever an earlier release left it
+        let dragged = self.dragged.unwrap_or(motion.current) + delta;
+        self.dragged = Some(dragged);
+        motion.set_value_at(self.overscroll().apply(dragged), now);
+    }
+
+    /// Springs from the end of a drag to the snap point nearest where its momentum would
+    /// carry the offset, or to that point itself, kept within bounds, without snap points
+    fn release(&mut self, motion: &mut Motion<f32>, now: Instant) {
+        let dragged = self.dragged.take();
+        let velocity = motion
+            .dragged_at
+            .filter(|at| now.saturating_duration_since(*at) <= DRAG_VELOCITY_TIMEOUT)
+            .map_or(0.0, |_| motion.velocity);
+        let (min, max) = self.bounds;
+        let resting = (motion.current + Decay::default().projected_distance(velocity))
+            .max(min)
+            .min(max);
+        let target = self
+            .snap_points
+            .iter()
+            .copied()
+            .min_by(|a, b| (a - resting).abs().total_cmp(&(b - resting).abs()))
+            .unwrap_or(resting);
+        let config = AnimationConfig::new(AnimationMode::Spring(Spring::default()));
+        motion.set_bounds(Some(self.overscroll()));
+        motion.try_animate_to_at(target, config, now);
+        // Carry on from the stretched position, so the resistance isn't applied twice
+        motion.unbounded = dragged.map(|dragged| (motion.current, dragged));
+    }
+}
+
+/// A scroll offset that rubber-bands past its bounds and springs to snap points on release
+///
+/// Feed it the deltas of a drag or wheel gesture with [`ScrollMotion::animate_by`], which
+/// follows them directly inside the bounds and with growing resistance past them, and
+/// call [`ScrollMotion::release`] when the gesture ends. The offset then springs, keeping
+/// the gesture's momentum, to the snap point closest to where a fling would have come to
+/// rest, pulled back within bounds with the same resistance if it overshoots.
+#[derive(Clone, Copy)]
+pub struct ScrollMotion {
+    offset: Signal<Motion<f32>>,
+    state: Signal<ScrollState>,
+}
+
+impl ScrollMotion {
+    /// Moves the offset by a scroll delta, stopping any snap in progress
+    pub fn animate_by(&mut self, delta: f32) {
+        let mut offset = self.offset;
+        let now = offset.peek().now();
+        self.state
+            .write()
+            .scroll_by(&mut offset.write(), delta, now);
+    }
+
+    /// Ends the gesture, springing to the nearest snap point
+    pub fn release(&mut self) {
+        let mut state = self.state;
+        ticker::drive(self.offset, |motion| {
+            let now = motion.now();
+            state.write().release(motion, now);
+        });
+    }
+
+    /// Current offset
+    pub fn get_value(&self) -> f32 {
+        self.offset.get_value()
+    }
+
+    /// Snap point the offset is heading to, or where it rests
+    pub fn get_target(&self) -> f32 {
+        self.offset.get_target()
+    }
+
+    /// True while springing to a snap point
+    pub fn is_running(&self) -> bool {
+        self.offset.is_running()
+    }
+}
+
+/// Creates a scroll offset that rubber-bands past `bounds` and snaps to `snap_points`
+///
+/// The offset starts at the lower bound. Without snap points a release springs to
+/// wherever the fling would have stopped, kept within the bounds.
+///
+/// # Example
+///
+/// ```no_run
+/// use dioxus_motion::prelude::*;
+/// use dioxus::prelude::*;
+///
+/// fn app() -> Element {
+///     // Three 300px pages
+///     let mut scroll = use_scroll_motion(vec![0.0, 300.0, 600.0], (0.0, 600.0));
+///     let mut last_x = use_signal(|| None::<f64>);
+///
+///     rsx! {
+///         div {
+///             onpointermove: move |event| {
+///                 let x = event.client_coordinates().x;
+///                 if let Some(last) = last_x.replace(Some(x)) {
+///                     scroll.animate_by((last - x) as f32);
+///                 }
+///             },
+///             onpointerup: move |_| {
+///                 last_x.set(None);
+///                 scroll.release();
+///             },
+///             div { style: "transform: translateX({-scroll.get_value()}px)" }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_scroll_motion(snap_points: Vec<f32>, bounds: (f32, f32)) -> ScrollMotion {
+    use_hook(|| ScrollMotion {
+        offset: Signal::new(Motion::new(bounds.0)),
+        state: Signal::new(ScrollState {
+            snap_points,
+            bounds,
+            dragged: None,
+        }),
+    })
+}
+
+/// One keyed animation in a [`MotionMap`]
+struct MotionEntry<K, T: Animatable> {
+    key: K,
+    motion: Signal<Motion<T>>,
+    exiting: bool,
+}
+
+/// Animations keyed by id, for lists whose items enter and leave
+///
+/// Each key gets its own motion, created the first time the key is inserted, so an item's
+/// animation follows it however the list is reordered. The lifecycle of a key is:
+///
+/// - [`insert`](Self::insert) enters it, animating a new key from a starting value to its
+///   resting value. Inserting a key that is still there retargets it instead, which also
+///   brings back a key that is on its way out.
+/// - [`remove`](Self::remove) starts its exit animation. The key stays in
+///   [`entries`](Self::entries), marked as exiting, so the item can still be rendered
+///   while it animates out.
+/// - Once the exit animation finishes the key is left out of `entries`, and its motion is
+///   dropped on the next `insert` or `remove`. That's why the map hands out values, through
+///   `entries` and [`get`](Self::get), rather than the animations themselves.
+pub struct MotionMap<K: 'static, T: Animatable> {
+    entries: Signal<Vec<MotionEntry<K, T>>>,
+}
+
+impl<K: 'static, T: Animatable> Clone for MotionMap<K, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K: 'static, T: Animatable> Copy for MotionMap<K, T> {}
+
+impl<K: PartialEq + Clone + 'static, T: Animatable> MotionMap<K, T> {
+    /// Enters `key`, animating it from `from` to `to`
+    ///
+    /// A key that is already there, even one that's exiting, animates to `to` from where
+    /// it is and `from` is ignored.
+    pub fn insert(&mut self, key: K, from: T, to: T, config: AnimationConfig) {
+        self.reap();
+        let owner = self.entries.origin_scope();
+        let mut entries = self.entries.write();
+        let existing = entries.iter_mut().find(|entry| entry.key == key);
+        let mut motion = if let Some(entry) = existing {
+            entry.exiting = false;
+            entry.motion
+        } else {
+            let motion = Signal::new_in_scope(Motion::new(from), owner);
+            entries.push(MotionEntry {
+                key,
+                motion,
+                exiting: false,
+            });
+            motion
+        };
+        drop(entries);
+        motion.animate_to(to, config);
+    }
+
+    /// Starts the exit of `key`, animating it to `to` before it's dropped
+    ///
+    /// Does nothing for keys that aren't in the map.
+    pub fn remove(&mut self, key: &K, to: T, config: AnimationConfig) {
+        self.reap();
+        let motion = self
+            .entries
+            .write()
+            .iter_mut()
+            .find(|entry| entry.key == *key)
+            .map(|entry| {
+                entry.exiting = true;
+                entry.motion
+            });
+        if let Some(mut motion) = motion {
+            motion.animate_to(to, config);
+        }
+    }
+
+    /// Current value of `key`, including one that is exiting
+    ///
+    /// Hands out the value rather than the animation, since a key's animation is dropped
+    /// once its exit has finished.
+    pub fn get(&self, key: &K) -> Option<T> {
+        self.motion(key).map(|motion| motion.get_value())
+    }
+
+    /// The animation of `key`, only valid until the key is reaped
+    fn motion(&self, key: &K) -> Option<Signal<Motion<T>>> {
+        self.entries
+            .read()
+            .iter()
+            .find(|entry| entry.key == *key)
+            .map(|entry| entry.motion)
+    }
+
+    /// Every key with its current value, in the order they were first inserted
+    ///
+    /// Exiting keys are included until their exit animation finishes, with `true` as
+    /// their third element.
+    pub fn entries(&self) -> Vec<(K, T, bool)> {
+        self.entries
+            .read()
+            .iter()
+            .filter(|entry| !entry.exiting || entry.motion.is_running())
+            .map(|entry| (entry.key.clone(), entry.motion.get_value(), entry.exiting))
+            .collect()
+    }
+
+    /// Drops the keys whose exit animation has finished
+    fn reap(&mut self) {
+        let finished =
+            |entry: &MotionEntry<K, T>| entry.exiting && !entry.motion.peek().is_running();
+        if !self.entries.peek().iter().any(finished) {
+            return;
+        }
+        self.entries.write().retain(|entry| {
+            let keep = !finished(entry);
+            if !keep {
+                entry.motion.manually_drop();
+            }
+            keep
+        });
+    }
+}
+
+/// Creates an empty map of keyed animations, see [`MotionMap`] for the lifecycle of a key
+///
+/// # Example
+///
+/// ```no_run
+/// use dioxus_motion::prelude::*;
+/// use dioxus::prelude::*;
+///
+/// fn app() -> Element {
+///     let mut items = use_motion_map::<u32, f32>();
+///     let mut next_id = use_signal(|| 0);
+///     let fade = || AnimationConfig::new(AnimationMode::Tween(Tween::default()));
+///
+///     rsx! {
+///         button {
+///             onclick: move |_| {
+///                 items.insert(next_id(), 0.0, 1.0, fade());
+///                 next_id += 1;
+///             },
+///             "Add"
+///         }
+///         for (id, opacity, exiting) in items.entries() {
+///             div {
+///                 key: "{id}",
+///                 style: "opacity: {opacity}",
+///                 onclick: move |_| {
+///                     if !exiting {
+///                         items.remove(&id, 0.0, fade());
+///                     }
+///                 },
+///                 "Item {id}"
+///             }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_motion_map<K: PartialEq + Clone + 'static, T: Animatable>() -> MotionMap<K, T> {
+    use_hook(|| MotionMap {
+        entries: Signal::new(Vec::new()),
+    })
+}
+
+/// Endpoints and on/off state of a [`MotionToggle`]
+struct ToggleState<T: Animatable> {
+    off: T,
+    on: T,
+    config: AnimationConfig,
+    is_on: bool,
+}
+
+/// A value animated between an off and an on endpoint, for switches, checkboxes and
+/// expand/collapse
+///
+/// The on/off state changes as soon as [`toggle`](Self::toggle) or [`set`](Self::set) is
+/// called, while the value animates over. Switching again before it arrives retargets the
+/// animation from where the value is, so rapid toggling reverses smoothly rather than
+/// jumping back to an endpoint.
+#[derive(Clone, Copy)]
+pub struct MotionToggle<T: Animatable> {
+    motion: Signal<Motion<T>>,
+    state: Signal<ToggleState<T>>,
+}
+
+impl<T: Animatable> MotionToggle<T> {
+    /// Flips the state and animates to the other endpoint
+    pub fn toggle(&mut self) {
+        let is_on = self.is_on();
+        self.set(!is_on);
+    }
+
+    /// Animates to the on endpoint if `on` is true and the off endpoint otherwise
+    ///
+    /// Does nothing if the toggle is already in that state.
+    pub fn set(&mut self, on: bool) {
+        let (target, config) = {
+            let mut state = self.state.write();
+            if state.is_on == on {
+                return;
+            }
+            state.is_on = on;
+            (if on { state.on } else { state.off }, state.config.clone())
+        };
+        self.motion.animate_to(target, config);
+    }
+
+    /// Whether the toggle is on, even while the value is still on its way
+    pub fn is_on(&self) -> bool {
+        self.state.read().is_on
+    }
+
+    /// Current value
+    pub fn get_value(&self) -> T {
+        self.motion.get_value()
+    }
+
+    /// True while animating between the endpoints
+    pub fn is_running(&self) -> bool {
+        self.motion.is_running()
+    }
+
+    /// The underlying animation, for anything else [`AnimationManager`] offers
+    pub fn motion(&self) -> impl AnimationManager<T> + use<T> {
+        self.motion
+    }
+}
+
+/// Creates a value that starts at `off` and animates between `off` and `on` with `config`
+///
+/// # Example
+///
+/// ```no_run
+/// use dioxus_motion::prelude::*;
+/// use dioxus::prelude::*;
+///
+/// fn app() -> Element {
+///     let mut knob = use_motion_toggle(
+///         0.0f32,
+///         20.0,
+///         AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+///     );
+///
+///     rsx! {
+///         button {
+///             role: "switch",
+///             "aria-checked": "{knob.is_on()}",
+///             onclick: move |_| knob.toggle(),
+///             span { style: "transform: translateX({knob.get_value()}px)" }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_motion_toggle<T: Animatable>(off: T, on: T, config: AnimationConfig) -> MotionToggle<T> {
+    use_hook(|| MotionToggle {
+        motion: Signal::new(Motion::new(off)),
+        state: Signal::new(ToggleState {
+            off,
+            on,
+            config,
+            is_on: false,
+        }),
+    })
+}
+
+// Reuse allocations for common operations
+thread_local! {
+    static TRANSFORM_BUFFER: RefCell<Vec<Transform>> = RefCell::new(Vec::with_capacity(32));
+    static SPRING_BUFFER: RefCell<Vec<SpringState>> = RefCell::new(Vec::with_capacity(16));
+}
+
+/// Represents a single keyframe in an animation
+#[derive(Clone)]
+pub struct Keyframe<T: Animatable> {
+    /// The target value at this keyframe
+    value: T,
+    /// Timing as a percentage (0.0 to 1.0)
+    offset: f32,
+    /// Optional easing for the segment leading up to this keyframe
+    easing: Option<Easing>,
+}
+/// Keyframe animation configuration
+#[derive(Clone)]
+pub struct KeyframeAnimation<T: Animatable> {
+    keyframes: Vec<Keyframe<T>>,
+    duration: Duration,
+}
+
+impl<T: Animatable> KeyframeAnimation<T> {
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            keyframes: Vec::new(),
+            duration,
+        }
+    }
+
+    /// Adds a keyframe at `offset`, from 0.0 to 1.0 through the animation
+    ///
+    /// `easing` shapes the segment leading up to this keyframe, or `None` keeps it linear.
+    /// It takes anything a tween does, converted with `.into()`: a [`NamedEasing`],
+    /// including the `CubicBezier` curves of [`Tween::bezier`], or an easing function.
+    pub fn add_keyframe(mut self, value: T, offset: f32, easing: Option<Easing>) -> Self {
+        self.keyframes.push(Keyframe {
+            value,
+            offset: offset.clamp(0.0, 1.0),
+            easing,
+        });
+        self.keyframes.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+        self
+    }
+
+    /// Samples the animation at an overall progress between 0.0 and 1.0
+    ///
+    /// Progress before the first keyframe or after the last one holds the nearest
+    /// keyframe's value. Returns `None` when there are no keyframes.
+    pub fn value_at(&self, progress: f32) -> Option<T> {
+        let first = self.keyframes.first()?;
+        let last = self.keyframes.last()?;
+        if progress <= first.offset {
+            return Some(first.value);
+        }
+        if progress >= last.offset {
+            return Some(last.value);
+        }
+
+        let (start, end) = self
+            .keyframes
+            .windows(2)
+            .find(|w| progress >= w[0].offset && progress <= w[1].offset)
+            .map(|w| (&w[0], &w[1]))?;
+        let span = end.offset - start.offset;
+        let local_progress = if span > 0.0 {
+            (progress - start.offset) / span
+        } else {
+            1.0
+        };
+
+        // The easing of the keyframe being approached shapes the segment leading to it
+        let eased_progress = end.easing.as_ref().map_or(local_progress, |easing| {
+            easing.ease(local_progress, 0.0, 1.0, 1.0)
+        });
+        Some(start.value.interpolate(&end.value, eased_progress))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animations::tween::ease_in_quad;
+    use crate::prelude::{Color, ColorSpace, Rotation3D, Vector2D};
+    use std::sync::Mutex;
+
+    const FRAME: f32 = 1.0 / 60.0;
+
+    /// Steps the motion until it stops running, returning every observed value
+    fn run_to_completion(motion: &mut Motion<f32>) -> Vec<f32> {
+        let mut values = Vec::new();
+        for _ in 0..2000 {
+            if !motion.is_running() {
+                break;
+            }
+            motion.update(FRAME);
+            values.push(motion.get_value());
+        }
+        values
+    }
+
+    #[test]
+    fn test_ping_pong_tween_returns_to_start() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            AnimationConfig::new(AnimationMode::Tween(Tween::new(Duration::from_millis(100))))
+                .with_loop(LoopMode::PingPongTimes(1)),
+        );
+
+        let values = run_to_completion(&mut motion);
+
+        assert!(!motion.is_running());
+        assert!(values.iter().any(|v| (v - 100.0).abs() < f32::EPSILON));
+        assert_eq!(motion.get_value(), 0.0);
+    }
+
+    #[test]
+    fn test_ping_pong_spring_returns_to_start() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            AnimationConfig::new(AnimationMode::Spring(Spring::default()))
+                .with_loop(LoopMode::PingPongTimes(1)),
+        );
+
+        let values = run_to_completion(&mut motion);
+
+        assert!(!motion.is_running());
+        assert!(values.iter().any(|v| *v >= 100.0));
+        assert!(motion.get_value().abs() < 0.01);
+    }
+
+    #[test]
+    fn test_ping_pong_spring_carries_its_speed_through_the_turn() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            AnimationConfig::new(AnimationMode::Spring(Spring::default()))
+                .with_loop(LoopMode::PingPongTimes(2)),
+        );
+
+        let mut turns = 0;
+        for _ in 0..2000 {
+            let value = motion.get_value();
+            match motion.update_status(FRAME) {
+                AnimationFrameStatus::LoopedThisFrame => {
+                    turns += 1;
+                    if turns == 1 {
+                        // Turned on arrival, still moving outwards and without a jump
+                        assert!(motion.get_value() >= 100.0);
+                        assert!(motion.get_value() - value < 10.0);
+                        assert!(motion.get_velocity() > 100.0);
+                    }
+                }
+                AnimationFrameStatus::CompletedThisFrame => break,
+                _ => {}
+            }
+        }
+        assert_eq!(turns, 3);
+        // The last leg settles back at the start
+        assert!(!motion.is_running());
+        assert!(motion.get_value().abs() < 0.01);
+    }
+
+    #[test]
+    fn test_velocity_reads_zero_when_idle() {
+        let mut motion = Motion::new(0.0f32);
+        assert_eq!(motion.get_velocity(), 0.0);
+
+        motion.animate_to(
+            100.0,
+            AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+        );
+        motion.update(FRAME);
+        assert!(motion.get_velocity() > 0.0);
+
+        motion.stop();
+        assert_eq!(motion.get_velocity(), 0.0);
+    }
+
+    #[test]
+    fn test_animate_to_with_velocity_keeps_seed() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to_with_velocity(
+            100.0,
+            AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+            -500.0,
+        );
+        assert_eq!(motion.get_velocity(), -500.0);
+
+        // The seeded velocity pulls the spring away from the target at first
+        motion.update(FRAME);
+        assert!(motion.get_value() < 0.0);
+    }
+
+    #[test]
+    fn test_pause_freezes_and_resume_continues() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+        );
+        motion.update(FRAME);
+
+        motion.pause();
+        let (value, velocity, elapsed) = (motion.current, motion.velocity, motion.elapsed);
+        for _ in 0..10 {
+            assert!(motion.update(FRAME));
+        }
+        assert!(motion.is_running());
+        assert_eq!(
+            (motion.current, motion.velocity, motion.elapsed),
+            (value, velocity, elapsed)
+        );
+
+        motion.resume();
+        motion.update(FRAME);
+        assert!(motion.get_value() > value);
+    }
+
+    fn tween(millis: u64) -> AnimationConfig {
+        AnimationConfig::new(AnimationMode::Tween(Tween::new(Duration::from_millis(
+            millis,
+        ))))
+    }
+
+    #[test]
+    fn test_sequence_step_delay_holds_value() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(AnimationSequence::new().then(50.0, tween(100)).then_after(
+            100.0,
+            tween(100),
+            Duration::from_millis(200),
+        ));
+
+        let values = run_to_completion(&mut motion);
+
+        // The gap keeps the value parked on the first step's target for ~200ms
+        let held_frames = values.iter().filter(|v| **v == 50.0).count();
+        assert!(held_frames as f32 * FRAME >= 0.2);
+        assert_eq!(motion.get_value(), 100.0);
+    }
+
+    #[test]
+    fn test_animate_to_cancels_pending_step_delay() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(AnimationSequence::new().then(50.0, tween(100)).then_after(
+            100.0,
+            tween(100),
+            Duration::from_secs(10),
+        ));
+        for _ in 0..20 {
+            motion.update(FRAME);
+        }
+        assert_eq!(motion.get_value(), 50.0);
+
+        motion.animate_to(0.0, tween(100));
+        motion.update(FRAME);
+        assert!(motion.get_value() < 50.0);
+    }
+
+    #[test]
+    fn test_sequence_on_step_fires_before_on_complete() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let step_events = events.clone();
+        let complete_events = events.clone();
+
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(
+            AnimationSequence::new()
+                .then(10.0, tween(50))
+                .then(20.0, tween(50))
+                .then(30.0, tween(50))
+                .on_step(move |index| step_events.borrow_mut().push(Some(index)))
+                .on_complete(move || complete_events.borrow_mut().push(None)),
+        );
+
+        run_to_completion(&mut motion);
+
+        assert_eq!(*events.borrow(), vec![Some(0), Some(1), Some(2), None]);
+    }
+
+    #[test]
+    fn test_vector_spring_settles_on_both_axes() {
+        let target = Vector2D::new(100.0, -40.0);
+        let mut motion = Motion::new(Vector2D::zero());
+        motion.animate_to(
+            target,
+            AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+        );
+
+        for _ in 0..2000 {
+            if !motion.update(FRAME) {
+                break;
+            }
+        }
+
+        assert!(!motion.is_running());
+        assert_eq!(motion.get_value(), target);
+    }
+
+    #[test]
+    fn test_array_spring_converges_on_every_element() {
+        let target = [100.0, -40.0, 7.5];
+        let mut motion = Motion::new([0.0f32; 3]);
+        motion.animate_to(
+            target,
+            AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+        );
+
+        for _ in 0..2000 {
+            if !motion.update(FRAME) {
+                break;
+            }
+        }
+
+        assert!(!motion.is_running());
+        assert_eq!(motion.get_value(), target);
+    }
+
+    #[test]
+    fn test_analytic_spring_matches_integrated() {
+        // The web build integrates with semi-implicit Euler, which strays further than RK4
+        let tolerance = if cfg!(feature = "web") { 5.0 } else { 0.1 };
+        for spring in [Spring::default(), Spring::wobbly(), Spring::slow()] {
+            let mode = AnimationMode::Spring(spring);
+            let mut integrated = Motion::new(0.0f32);
+            integrated.animate_to(100.0, AnimationConfig::new(mode.clone()));
+            let mut analytic = Motion::new(0.0f32);
+            analytic.animate_to(
+                100.0,
+                AnimationConfig::new(mode).with_spring_solver(SpringSolver::Analytic),
+            );
+
+            for _ in 0..120 {
+                integrated.update(FRAME);
+                analytic.update(FRAME);
+                assert!((integrated.get_value() - analytic.get_value()).abs() < tolerance);
+            }
+        }
+    }
+
+    #[test]
+    fn test_analytic_spring_ignores_frame_rate() {
+        let config = AnimationConfig::new(AnimationMode::Spring(Spring::default()))
+            .with_spring_solver(SpringSolver::Analytic);
+        let mut smooth = Motion::new(0.0f32);
+        smooth.animate_to(100.0, config.clone());
+        let mut choppy = Motion::new(0.0f32);
+        choppy.animate_to(100.0, config);
+
+        for _ in 0..4 {
+            for _ in 0..6 {
+                smooth.update(0.02);
+            }
+            choppy.update(0.12);
+            assert!((smooth.get_value() - choppy.get_value()).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn test_short_frames_still_advance() {
+        // 300Hz display frames, shorter than any frame the ticker used to skip
+        const SHORT_FRAME: f32 = 1.0 / 300.0;
+        let mut tween_motion = Motion::new(0.0f32);
+        tween_motion.animate_to(100.0, tween(1000).with_delay(Duration::from_millis(100)));
+        let mut spring_motion = Motion::new(0.0f32);
+        spring_motion.animate_to(
+            100.0,
+            AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+        );
+
+        for _ in 0..60 {
+            tween_motion.update(SHORT_FRAME);
+            spring_motion.update(SHORT_FRAME);
+        }
+        // Through the delay and into the tween
+        assert!(tween_motion.get_value() > 0.0);
+        assert!(spring_motion.get_value() > 0.0);
+
+        for _ in 0..1500 {
+            tween_motion.update(SHORT_FRAME);
+            spring_motion.update(SHORT_FRAME);
+        }
+        assert!(!tween_motion.is_running());
+        assert_eq!(tween_motion.get_value(), 100.0);
+        assert!(!spring_motion.is_running());
+        assert_eq!(spring_motion.get_value(), 100.0);
+    }
+
+    #[test]
+    fn test_animate_by_offsets_current_value() {
+        let mut motion = Motion::new(Vector2D::new(5.0, 5.0));
+        motion.animate_by(Vector2D::new(10.0, -5.0), tween(100));
+        for _ in 0..20 {
+            motion.update(FRAME);
+        }
+        assert_eq!(motion.get_value(), Vector2D::new(15.0, 0.0));
+
+        // A nudge mid-flight starts from wherever the value is at that moment
+        motion.animate_by(Vector2D::new(10.0, 0.0), tween(100));
+        motion.update(FRAME);
+        motion.animate_by(Vector2D::new(-20.0, 0.0), tween(100));
+        let current = motion.get_value();
+        assert_eq!(motion.target, current.add(&Vector2D::new(-20.0, 0.0)));
+    }
+
+    #[test]
+    fn test_retargeting_to_same_target_keeps_running_animation() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let mut motion = Motion::new(0.0f32);
+        assert!(motion.try_animate_to(100.0, tween(1000)));
+        for _ in 0..10 {
+            motion.update(FRAME);
+        }
+        let elapsed = motion.elapsed;
+
+        assert!(!motion.try_animate_to(100.0, tween(1000)));
+        assert!(!motion.try_animate_to(100.0 + f32::epsilon() / 2.0, tween(1000)));
+        assert_eq!(motion.elapsed, elapsed);
+
+        // `animate_to` always takes the new config, callbacks included
+        let completed = Arc::new(AtomicBool::new(false));
+        let done = completed.clone();
+        motion.animate_to(
+            100.0,
+            tween(100).with_on_complete(move || done.store(true, Ordering::Relaxed)),
+        );
+        assert_eq!(motion.elapsed, Duration::ZERO);
+        run_to_completion(&mut motion);
+        assert!(completed.load(Ordering::Relaxed));
+
+        assert!(motion.try_animate_to(50.0, tween(1000)));
+        assert_eq!(motion.elapsed, Duration::ZERO);
+
+        // Once finished, the same target starts a fresh animation
+        run_to_completion(&mut motion);
+        assert!(motion.try_animate_to(50.0, tween(1000)));
+    }
+
+    #[test]
+    fn test_completion_epsilon_controls_spring_settling() {
+        let frames = |config: AnimationConfig| {
+            let mut motion = Motion::new(0.0f32);
+            motion.animate_to(2000.0, config);
+            let frames = run_to_completion(&mut motion).len();
+            assert_eq!(motion.get_value(), 2000.0);
+            frames
+        };
+        let spring = || AnimationConfig::new(AnimationMode::Spring(Spring::default()));
+
+        let default = frames(spring());
+        let loose = frames(spring().with_completion_epsilon(0.5));
+        assert!(loose < default, "{loose} >= {default}");
+    }
+
+    #[test]
+    fn test_set_value_interrupts_animation() {
+        let spring = AnimationConfig::new(AnimationMode::Spring(Spring::default()));
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, spring.clone());
+        motion.update(FRAME);
+
+        // A drag takes over mid-flight and moves the value directly
+        for x in [20.0, 25.0, 30.0] {
+            motion.set_value(x);
+            assert_eq!(motion.get_value(), x);
+            assert!(!motion.is_running());
+        }
+
+        // Releasing hands off to a spring from where the drag left the value
+        motion.animate_to_with_velocity(0.0, spring, 300.0);
+        motion.update(FRAME);
+        assert!(motion.get_value() > 30.0);
+        run_to_completion(&mut motion);
+        assert_eq!(motion.get_value(), 0.0);
+    }
+
+    #[test]
+    fn test_set_value_tracks_drag_velocity() {
+        let spring = || AnimationConfig::new(AnimationMode::Spring(Spring::default()));
+        let frame = Duration::from_millis(16);
+        let now = Time::now();
+        let mut motion = Motion::new(0.0f32);
+
+        // 8px every 16ms is 500px/s
+        motion.set_value_at(0.0, now - frame * 2);
+        assert_eq!(motion.get_velocity(), 0.0);
+        motion.set_value_at(8.0, now - frame);
+        motion.set_value_at(16.0, now);
+        assert!((motion.get_velocity() - 500.0).abs() < 0.1);
+
+        motion.animate_to(16.0, spring());
+        assert!((motion.get_velocity() - 500.0).abs() < 0.1);
+        motion.update(FRAME);
+        assert!(motion.get_value() > 16.0);
+
+        // A drag that paused before release has nothing left to hand over
+        let mut motion = Motion::new(0.0f32);
+        motion.set_value_at(0.0, now - Duration::from_secs(1) - frame);
+        motion.set_value_at(8.0, now - Duration::from_secs(1));
+        motion.animate_to(8.0, spring());
+        assert_eq!(motion.get_velocity(), 0.0);
+
+        motion.set_value(8.0);
+        motion.stop();
+        assert_eq!(motion.get_velocity(), 0.0);
+    }
+
+    #[test]
+    fn test_energy_completion_cuts_soft_spring_tail() {
+        let soft = Spring {
+            stiffness: 20.0,
+            damping: 12.0,
+            mass: 1.0,
+            velocity: 0.0,
+        };
+        let frames = |config: AnimationConfig| {
+            let mut motion = Motion::new(0.0f32);
+            motion.animate_to(100.0, config);
+            let frames = run_to_completion(&mut motion).len();
+            assert_eq!(motion.get_value(), 100.0);
+            frames
+        };
+        let config = || AnimationConfig::new(AnimationMode::Spring(soft));
+
+        let thresholds = frames(config());
+        let energy = frames(config().with_spring_completion(SpringCompletion::Energy(1.0)));
+        assert!(energy < thresholds, "{energy} >= {thresholds}");
+    }
+
+    #[test]
+    fn test_output_easing_reshapes_only_the_observed_value() {
+        let config = || {
+            AnimationConfig::new(AnimationMode::Spring(Spring {
+                stiffness: 200.0,
+                damping: 10.0,
+                mass: 1.0,
+                velocity: 0.0,
+            }))
+        };
+        let mut raw = Motion::new(0.0f32);
+        let mut shaped = Motion::new(0.0f32);
+        raw.animate_to(100.0, config());
+        shaped.animate_to(
+            100.0,
+            config().with_output_easing(|t: f32, b, c, _| b + c * t * t),
+        );
+
+        let (mut raw_peak, mut shaped_peak) = (0.0f32, 0.0f32);
+        while raw.update(FRAME) {
+            assert!(shaped.update(FRAME));
+            // Same physics underneath
+            assert_eq!(raw.get_velocity(), shaped.get_velocity());
+            raw_peak = raw_peak.max(raw.get_value());
+            shaped_peak = shaped_peak.max(shaped.get_value());
+        }
+        assert!(!shaped.update(FRAME));
+
+        // Squared progress lags on the way there and exaggerates the overshoot
+        assert!(raw_peak > 100.0);
+        assert!(shaped_peak > raw_peak, "{shaped_peak} <= {raw_peak}");
+        assert_eq!(shaped.get_value(), 100.0);
+    }
+
+    #[test]
+    fn test_output_easing_never_jumps_when_interrupted() {
+        let squared = |config: AnimationConfig| {
+            config.with_output_easing(|t: f32, b: f32, c: f32, _: f32| b + c * t * t)
+        };
+        let halfway = || {
+            let mut motion = Motion::new(0.0f32);
+            motion.animate_to(100.0, squared(tween(1000)));
+            motion.update(0.5);
+            assert_eq!(motion.get_value(), 25.0);
+            motion
+        };
+
+        // A new animation starts from the value shown, not the one underneath
+        let mut motion = halfway();
+        motion.animate_to(0.0, squared(tween(1000)));
+        assert_eq!(motion.get_value(), 25.0);
+
+        // Stopping leaves the value where it was shown
+        let mut motion = halfway();
+        motion.stop();
+        assert_eq!(motion.get_value(), 25.0);
+
+        // A retargeted spring carries on from where it was shown
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            squared(AnimationConfig::new(AnimationMode::Spring(
+                Spring::default(),
+            ))),
+        );
+        for _ in 0..10 {
+            motion.update(FRAME);
+        }
+        let shown = motion.get_value();
+        motion.retarget(200.0);
+        assert_eq!(motion.get_value(), shown);
+        motion.update(FRAME);
+        assert!((motion.get_value() - shown).abs() < 5.0);
+    }
+
+    #[test]
+    fn test_springs_settle_within_bounded_frames() {
+        let spring = |damping| Spring {
+            stiffness: 100.0,
+            damping,
+            mass: 1.0,
+            velocity: 0.0,
+        };
+        let frames = |config: AnimationConfig| {
+            let mut motion = Motion::new(0.0f32);
+            motion.animate_to(100.0, config);
+            let frames = run_to_completion(&mut motion).len();
+            assert!(!motion.is_running());
+            assert_eq!(motion.get_value(), 100.0);
+            frames
+        };
+        let config = |damping| AnimationConfig::new(AnimationMode::Spring(spring(damping)));
+        let any = SpringCompletion::AnyThreshold;
+
+        // Overdamped: creeps up on the target, so stopping once it's nearly still saves the tail
+        let overdamped = frames(config(40.0));
+        let overdamped_any = frames(config(40.0).with_spring_completion(any));
+        assert!(overdamped < 1000, "{overdamped}");
+        assert!(
+            overdamped_any < overdamped,
+            "{overdamped_any} >= {overdamped}"
+        );
+
+        // Underdamped: bounces, but still settles
+        let underdamped = frames(config(5.0));
+        let underdamped_any = frames(config(5.0).with_spring_completion(any));
+        assert!(underdamped < 1000, "{underdamped}");
+        assert!(underdamped_any <= underdamped);
+    }
+
+    #[test]
+    fn test_retarget_keeps_spring_state() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+        );
+        for _ in 0..5 {
+            motion.update(FRAME);
+        }
+        let (config, velocity) = (motion.config.clone(), motion.velocity);
+
+        motion.retarget(50.0);
+        assert_eq!(motion.target, 50.0);
+        assert_eq!(motion.velocity, velocity);
+        assert!(Arc::ptr_eq(&motion.config, &config));
+
+        run_to_completion(&mut motion);
+        assert_eq!(motion.get_value(), 50.0);
+
+        // Idle motions start towards the new target with the last config
+        motion.retarget(80.0);
+        assert!(motion.is_running());
+        run_to_completion(&mut motion);
+        assert_eq!(motion.get_value(), 80.0);
+    }
+
+    #[test]
+    fn test_f64_tween_and_spring_reach_precise_targets() {
+        // Steps of a millionth of a degree are lost entirely in f32 this far from zero
+        let start = 51.477_928f64;
+        let target = 51.477_929f64;
+        assert_eq!(start as f32, target as f32);
+
+        let mut motion = Motion::new(start);
+        motion.animate_to(target, tween(100));
+        motion.update(0.05);
+        let halfway = motion.get_value();
+        assert!(halfway > start && halfway < target);
+        for _ in 0..10 {
+            motion.update(FRAME);
+        }
+        assert_eq!(motion.get_value(), target);
+
+        let mut motion = Motion::new(0.0f64);
+        motion.animate_to(
+            1.0e7,
+            AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+        );
+        for _ in 0..2000 {
+            if !motion.is_running() {
+                break;
+            }
+            motion.update(FRAME);
+        }
+        assert!(!motion.is_running());
+        assert_eq!(motion.get_value(), 1.0e7);
+    }
+
+    #[test]
+    fn test_snap_output_rounds_only_observed_value() {
+        let mut motion = Motion::new(Vector2D::new(0.0, 0.0));
+        motion.animate_to(
+            Vector2D::new(100.0, 50.0),
+            tween(1000).with_snap_output(2.0),
+        );
+        motion.update(0.123);
+
+        let observed = motion.get_value();
+        assert_eq!(observed, Vector2D::new(12.0, 6.0));
+        assert_ne!(motion.current, observed);
+        assert!((motion.current.x - 12.3).abs() < 1e-3);
+
+        // A non-positive grid leaves the value alone
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, tween(1000).with_snap_output(0.0));
+        motion.update(0.123);
+        assert_eq!(motion.get_value(), motion.current);
+    }
+
+    #[test]
+    fn test_global_motion_config() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let completed = Arc::new(AtomicBool::new(false));
+        let flag = completed.clone();
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            tween(1000)
+                .with_loop(LoopMode::Infinite)
+                .with_speed(4.0)
+                .with_on_complete(move || flag.store(true, Ordering::Relaxed)),
+        );
+
+        // Global speed stacks with the animation's own
+        let half_speed = MotionConfig {
+            global_speed: 0.5,
+            ..MotionConfig::default()
+        };
+        assert!(motion.advance(0.1, half_speed, || false).is_animating());
+        assert!((motion.get_value() - 20.0).abs() < 1e-3);
+
+        // Disabled animations finish on their next frame, even looping ones
+        let disabled = MotionConfig {
+            animations_enabled: false,
+            ..MotionConfig::default()
+        };
+        assert_eq!(
+            motion.advance(FRAME, disabled, || false),
+            AnimationFrameStatus::CompletedThisFrame
+        );
+        assert_eq!(motion.get_value(), 100.0);
+        assert!(!motion.is_running());
+        assert!(completed.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn test_get_target() {
+        let mut motion = Motion::new(10.0f32);
+        assert_eq!(motion.get_target(), 10.0);
+
+        motion.animate_to(50.0, tween(100));
+        motion.update(FRAME);
+        assert_eq!(motion.get_target(), 50.0);
+
+        // Stopped partway, the value rests where it is
+        motion.stop();
+        assert_eq!(motion.get_target(), motion.get_value());
+
+        motion.animate_sequence(
+            AnimationSequence::new()
+                .then(20.0, tween(50))
+                .then(30.0, tween(50)),
+        );
+        motion.update(FRAME);
+        assert_eq!(motion.get_target(), 20.0);
+    }
+
+    #[test]
+    fn test_bounds_shape_spring_overshoot() {
+        let peak = |bounds: Option<Bounds<f32>>| {
+            let mut motion = Motion::new(0.0f32);
+            motion.set_bounds(bounds);
+            motion.animate_to(
+                100.0,
+                AnimationConfig::new(AnimationMode::Spring(Spring::wobbly())),
+            );
+            let values = run_to_completion(&mut motion);
+            assert!(!motion.is_running());
+            assert_eq!(motion.get_value(), 100.0);
+            values.into_iter().fold(f32::MIN, f32::max)
+        };
+
+        let free = peak(None);
+        let clamped = peak(Some(Bounds::new(0.0, 100.0)));
+        let stretched = peak(Some(Bounds::new(0.0, 100.0).with_rubber_band(0.55)));
+        assert!(free > 100.0);
+        assert_eq!(clamped, 100.0);
+        assert!(stretched > 100.0 && stretched < free);
+    }
+
+    #[test]
+    fn test_clamped_spring_never_passes_target() {
+        let wobbly = || {
+            AnimationConfig::new(AnimationMode::Spring(Spring::wobbly())).with_clamp_to_target(true)
+        };
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, wobbly());
+        let values = run_to_completion(&mut motion);
+        assert!(values.iter().all(|&value| value <= 100.0));
+        assert!(!motion.is_running());
+        assert_eq!(motion.get_value(), 100.0);
+
+        // Each component stops at its own target, whichever way it's heading
+        let target = Transform::new(100.0, -50.0, 2.0, 0.0);
+        let mut motion = Motion::new(Transform::new(0.0, 0.0, 1.0, 0.0));
+        motion.animate_to(target, wobbly());
+        for _ in 0..2000 {
+            if !motion.update(FRAME) {
+                break;
+            }
+            let value = motion.get_value();
+            assert!(value.x <= 100.0 && value.y >= -50.0 && value.scale <= 2.0);
+        }
+        assert_eq!(motion.get_value(), target);
+    }
+
+    #[test]
+    fn test_bounds_hold_targets_outside_the_range() {
+        let mut motion = Motion::new(Vector2D::new(50.0, 50.0));
+        motion.set_bounds(Some(Bounds::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(100.0, 100.0),
+        )));
+        motion.animate_to(
+            Vector2D::new(150.0, 80.0),
+            AnimationConfig::new(AnimationMode::Spring(Spring::stiff())),
+        );
+        for _ in 0..2000 {
+            if !motion.update(FRAME) {
+                break;
+            }
+        }
+
+        // The spring still settles, with the value held at the edge it ran into
+        assert!(!motion.is_running());
+        assert_eq!(motion.get_value(), Vector2D::new(100.0, 80.0));
+    }
+
+    #[test]
+    fn test_decay_rests_where_momentum_runs_out() {
+        let fling = |friction: f32| {
+            let mut motion = Motion::new(0.0f32);
+            motion.animate_decay(
+                600.0,
+                AnimationConfig::new(AnimationMode::Decay(Decay::new(friction))),
+            );
+            run_to_completion(&mut motion);
+            assert!(!motion.is_running());
+            assert_eq!(motion.get_velocity(), 0.0);
+            motion.get_value()
+        };
+
+        let gentle = fling(2.0);
+        let firm = fling(6.0);
+        assert!((gentle - 300.0).abs() < 0.01);
+        assert!((firm - 100.0).abs() < 0.01);
+        assert!(firm < gentle);
+    }
+
+    #[test]
+    fn test_tuple_springs_converge_together() {
+        let spring = || AnimationConfig::new(AnimationMode::Spring(Spring::default()));
+
+        let pair_target = (100.0f32, 0.0f32);
+        let mut pair = Motion::new((0.0f32, 1.0f32));
+        pair.animate_to(pair_target, spring());
+
+        let triple_target = (Vector2D::new(50.0, -20.0), 0.5f32, 360.0f32);
+        let mut triple = Motion::new((Vector2D::default(), 1.0f32, 0.0f32));
+        triple.animate_to(triple_target, spring());
+
+        for _ in 0..2000 {
+            let pair_running = pair.update(FRAME);
+            let triple_running = triple.update(FRAME);
+            if !pair_running && !triple_running {
+                break;
+            }
+        }
+
+        assert!(!pair.is_running() && !triple.is_running());
+        assert_eq!(pair.get_value(), pair_target);
+        assert_eq!(triple.get_value(), triple_target);
+    }
+
+    #[test]
+    fn test_rotation_spring_settles() {
+        let target = Rotation3D::from_euler(20.0, 270.0, -45.0);
+        let mut motion = Motion::new(Rotation3D::identity());
+        motion.animate_to(
+            target,
+            AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+        );
+
+        for _ in 0..2000 {
+            if !motion.update(FRAME) {
+                break;
+            }
+        }
+
+        assert!(!motion.is_running());
+        assert_eq!(motion.get_value(), target);
+    }
+
+    #[test]
+    fn test_tween_uses_configured_color_space() {
+        let mut motion = Motion::new(Color::new(1.0, 0.0, 0.0, 1.0));
+        motion.animate_to(
+            Color::new(0.0, 1.0, 0.0, 1.0),
+            tween(1000).with_color_space(ColorSpace::Hsl),
+        );
+        for _ in 0..30 {
+            motion.update(FRAME);
+        }
+
+        // Halfway through an HSL tween from red to green is yellow, not grey
+        let mid = motion.get_value();
+        assert!(mid.r > 0.9 && mid.g > 0.9 && mid.b < 0.1);
+    }
+
+    #[test]
+    fn test_speed_stretches_tween() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, tween(100).with_speed(0.5));
+
+        // After the tween's nominal 100ms it is only halfway there
+        for _ in 0..6 {
+            motion.update(FRAME);
+        }
+        assert!(motion.is_running());
+        assert!((motion.get_value() - 50.0).abs() < 1.0);
+
+        let frames = run_to_completion(&mut motion).len();
+        assert!((5..=7).contains(&frames));
+        assert_eq!(motion.get_value(), 100.0);
+    }
+
+    #[test]
+    fn test_fast_spring_stays_stable() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            AnimationConfig::new(AnimationMode::Spring(Spring::default())).with_speed(4.0),
+        );
+
+        // Long frames at 4x speed must still converge rather than blow up
+        for _ in 0..200 {
+            motion.update(0.1);
+            assert!(motion.get_value().abs() < 200.0);
+        }
+        assert!(!motion.is_running());
+        assert_eq!(motion.get_value(), 100.0);
+    }
+
+    #[test]
+    fn test_on_update_reports_each_new_value() {
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = seen.clone();
+
+        let mut motion = Motion::new(0.0f32);
+        motion.on_update(move |value| {
+            if let Ok(mut values) = sink.lock() {
+                values.push(value);
+            }
+        });
+        motion.animate_to(100.0, tween(100));
+        let values = run_to_completion(&mut motion);
+
+        let reported = seen.lock().map(|v| v.clone()).unwrap_or_default();
+        assert_eq!(reported, values);
+        assert_eq!(reported.last(), Some(&100.0));
+
+        // Stays registered for the animations that follow
+        motion.animate_to(0.0, tween(100));
+        run_to_completion(&mut motion);
+        let reported = seen.lock().map(|v| v.last().copied()).unwrap_or_default();
+        assert_eq!(reported, Some(0.0));
+    }
+
+    #[test]
+    fn test_zero_bounce_spring_never_overshoots() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            AnimationConfig::new(AnimationMode::Spring(Spring::with_duration(
+                Duration::from_millis(500),
+                0.0,
+            ))),
+        );
+
+        let values = run_to_completion(&mut motion);
+
+        assert!(!motion.is_running());
+        assert!(values.iter().all(|v| *v <= 100.0));
+        assert_eq!(motion.get_value(), 100.0);
+    }
+
+    #[test]
+    fn test_reverse_tween_in_flight_continues_smoothly() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, tween(300));
+        for _ in 0..6 {
+            motion.update(FRAME);
+        }
+        let before = motion.get_value();
+
+        motion.reverse();
+        motion.update(FRAME);
+        let after = motion.get_value();
+        assert!(after < before && before - after < 10.0);
+
+        // Reversing again heads back to the original target from the same spot
+        motion.reverse();
+        motion.update(FRAME);
+        assert!(motion.get_value() > after && motion.get_value() - after < 10.0);
+
+        run_to_completion(&mut motion);
+        assert_eq!(motion.get_value(), 100.0);
+    }
+
+    #[test]
+    fn test_reverse_completed_animation_returns_to_start() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, tween(100));
+        run_to_completion(&mut motion);
+        assert_eq!(motion.get_value(), 100.0);
+
+        motion.reverse();
+        assert!(motion.is_running());
+        run_to_completion(&mut motion);
+        assert_eq!(motion.get_value(), 0.0);
+    }
+
+    #[test]
+    fn test_reverse_spring_keeps_velocity() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+        );
+        for _ in 0..5 {
+            motion.update(FRAME);
+        }
+        let velocity = motion.get_velocity();
+
+        motion.reverse();
+        assert_eq!(motion.get_velocity(), velocity);
+        assert_eq!(motion.target, 0.0);
+    }
+
+    #[test]
+    fn test_keyframes_pass_through_each_stop() {
+        let animation = KeyframeAnimation::new(Duration::from_millis(1000))
+            .add_keyframe(0.0f32, 0.0, None)
+            .add_keyframe(100.0, 0.5, None)
+            .add_keyframe(40.0, 1.0, None);
+
+        assert_eq!(animation.value_at(0.25), Some(50.0));
+        assert_eq!(animation.value_at(0.5), Some(100.0));
+        assert_eq!(animation.value_at(0.75), Some(70.0));
+    }
+
+    #[test]
+    fn test_keyframe_easing_takes_any_tween_easing() {
+        let animation = KeyframeAnimation::new(Duration::from_millis(1000))
+            .add_keyframe(0.0f32, 0.0, None)
+            .add_keyframe(100.0, 0.5, Some(ease_in_quad.into()))
+            .add_keyframe(0.0, 1.0, Some(NamedEasing::EaseOut.into()));
+
+        // Quadratic ease-in covers a quarter of the way by the middle of its segment
+        assert_eq!(animation.value_at(0.25), Some(25.0));
+        // Easing out on the way back down has covered more than half of it by then
+        assert!(animation.value_at(0.75).is_some_and(|value| value < 50.0));
+    }
+
+    #[test]
+    fn test_keyframes_clamp_outside_defined_offsets() {
+        let animation = KeyframeAnimation::new(Duration::from_millis(1000))
+            .add_keyframe(10.0f32, 0.2, None)
+            .add_keyframe(20.0, 0.8, None);
+
+        assert_eq!(animation.value_at(0.0), Some(10.0));
+        assert_eq!(animation.value_at(1.0), Some(20.0));
+        assert_eq!(
+            KeyframeAnimation::<f32>::new(Duration::from_millis(1000)).value_at(0.5),
+            None
+        );
+    }
+
+    #[test]
+    fn test_keyframes_complete_once_at_final_value() {
+        let completions = Arc::new(Mutex::new(0));
+        let counter = completions.clone();
+        let mut motion = Motion::new(0.0f32);
+        motion.config = Arc::new(AnimationConfig::default().with_on_complete(move || {
+            if let Ok(mut count) = counter.lock() {
+                *count += 1;
+            }
+        }));
+        motion.animate_keyframes(
+            KeyframeAnimation::new(Duration::from_millis(200))
+                .add_keyframe(0.0, 0.0, None)
+                .add_keyframe(100.0, 0.5, None)
+                .add_keyframe(50.0, 1.0, None),
+        );
+
+        let values = run_to_completion(&mut motion);
+        assert!(!motion.is_running());
+        assert_eq!(motion.get_value(), 50.0);
+        assert!(values.iter().any(|v| *v > 90.0));
+
+        motion.update(FRAME);
+        assert_eq!(completions.lock().map(|c| *c).unwrap_or_default(), 1);
+    }
+
+    #[test]
+    fn test_keyframes_wait_out_the_delay() {
+        let mut motion = Motion::new(0.0f32);
+        motion.config = Arc::new(AnimationConfig::default().with_delay(Duration::from_millis(100)));
+        motion.animate_keyframes(
+            KeyframeAnimation::new(Duration::from_millis(100))
+                .add_keyframe(0.0, 0.0, None)
+                .add_keyframe(100.0, 1.0, None),
+        );
+        assert!(motion.is_delayed());
+        assert_eq!(
+            motion.time_remaining().map(|left| left.as_millis()),
+            Some(200)
+        );
+
+        for _ in 0..5 {
+            motion.update(0.02);
+            assert_eq!(motion.get_value(), 0.0);
+        }
+        assert!(!motion.is_delayed());
+        // The next 50ms land halfway through the keyframes
+        motion.update(0.05);
+        assert!((motion.get_value() - 50.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_delay_holds_initial_value() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, tween(100).with_delay(Duration::from_millis(200)));
+
+        // 10 frames of 20ms cover the delay without exceeding it
+        for _ in 0..10 {
+            assert!(motion.update(0.02));
+            assert_eq!(motion.get_value(), 0.0);
+        }
+
+        motion.update(0.02);
+        assert!(motion.get_value() > 0.0);
+        run_to_completion(&mut motion);
+        assert_eq!(motion.get_value(), 100.0);
+    }
+
+    #[test]
+    fn test_loop_times_counts_past_u8() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(1.0, tween(20).with_loop(LoopMode::Times(300)));
+
+        let mut max_loop = 0;
+        for _ in 0..2000 {
+            if !motion.is_running() {
+                break;
+            }
+            motion.update(0.02);
+            max_loop = max_loop.max(motion.current_loop());
+        }
+
+        assert!(!motion.is_running());
+        assert_eq!(max_loop, 299);
+        // Stopping resets the counter for the next animation
+        assert_eq!(motion.current_loop(), 0);
+    }
+
+    #[test]
+    fn test_tween_progress_is_uneased_time() {
+        let mut motion = Motion::new(0.0f32);
+        assert_eq!(motion.get_progress(), 1.0);
+
+        motion.animate_to(
+            100.0,
+            AnimationConfig::new(AnimationMode::Tween(
+                Tween::new(Duration::from_millis(1000)).with_easing(ease_in_quad),
+            )),
+        );
+        assert_eq!(motion.get_progress(), 0.0);
+        for _ in 0..30 {
+            motion.update(FRAME);
+        }
+        assert!((motion.get_progress() - 0.5).abs() < 0.01);
+        assert!(motion.get_value() < 30.0);
+
+        run_to_completion(&mut motion);
+        assert_eq!(motion.get_progress(), 1.0);
+    }
+
+    #[test]
+    fn test_spring_progress_tracks_distance() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+        );
+        assert_eq!(motion.get_progress(), 0.0);
+
+        let mut last = 0.0;
+        for _ in 0..3 {
+            motion.update(FRAME);
+            let progress = motion.get_progress();
+            assert!(progress > last && progress <= 1.0);
+            assert!((progress - motion.get_value() / 100.0).abs() < 1e-4);
+            last = progress;
+        }
+
+        run_to_completion(&mut motion);
+        assert_eq!(motion.get_progress(), 1.0);
+    }
+
+    #[test]
+    fn test_seek_tween_applies_easing_without_running() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            AnimationConfig::new(AnimationMode::Tween(
+                Tween::new(Duration::from_millis(1000)).with_easing(ease_in_quad),
+            )),
+        );
+
+        motion.seek(0.5);
+        assert!(!motion.is_running());
+        assert_eq!(motion.get_value(), 25.0);
+        assert_eq!(motion.elapsed, Duration::from_millis(500));
+        assert!(!motion.update(FRAME));
+        assert_eq!(motion.get_value(), 25.0);
+
+        // Seeking again scrubs within the same range, clamped to its ends
+        motion.seek(2.0);
+        assert_eq!(motion.get_value(), 100.0);
+        motion.seek(-1.0);
+        assert_eq!(motion.get_value(), 0.0);
+    }
+
+    #[test]
+    fn test_arc_path_bends_around_the_straight_line() {
+        let tween = || {
+            AnimationConfig::new(AnimationMode::Tween(Tween::new(Duration::from_millis(
+                1000,
+            ))))
+        };
+
+        let mut straight = Motion::new(Vector2D::zero());
+        straight.animate_to(Vector2D::new(100.0, 0.0), tween());
+        straight.seek(0.5);
+        assert_eq!(straight.get_value(), Vector2D::new(50.0, 0.0));
+
+        // Heading right, positive curvature bends down the screen by a quarter of the distance
+        let mut arc = Motion::new(Vector2D::zero());
+        arc.animate_to(
+            Vector2D::new(100.0, 0.0),
+            tween().with_path(MotionPath::Arc(0.5)),
+        );
+        arc.seek(0.5);
+        assert_eq!(arc.get_value(), Vector2D::new(50.0, 25.0));
+        arc.seek(0.0);
+        assert_eq!(arc.get_value(), Vector2D::zero());
+
+        // Running the tween follows the same arc and still lands exactly on the target
+        let mut running = Motion::new(Vector2D::zero());
+        running.animate_to(
+            Vector2D::new(100.0, 0.0),
+            tween().with_path(MotionPath::Arc(0.5)),
+        );
+        running.update(0.5);
+        assert_eq!(running.get_value(), Vector2D::new(50.0, 25.0));
+        while running.update(FRAME) {}
+        assert_eq!(running.get_value(), Vector2D::new(100.0, 0.0));
+    }
+
+    #[test]
+    fn test_restored_snapshot_carries_on_identically() {
+        let spring = AnimationConfig::new(AnimationMode::Spring(Spring::wobbly()));
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, spring);
+        for _ in 0..10 {
+            motion.update(FRAME);
+        }
+        let snapshot = motion.snapshot();
+        let expected = run_to_completion(&mut motion);
+
+        motion.restore(snapshot);
+        assert_eq!(motion.snapshot(), snapshot);
+        assert_eq!(run_to_completion(&mut motion), expected);
+
+        // Restoring an idle snapshot stops whatever is running
+        let idle = motion.snapshot();
+        motion.animate_to(0.0, tween(100));
+        motion.restore(idle);
+        assert!(!motion.is_running());
+        assert_eq!(motion.get_value(), 100.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_config_round_trips_through_serde() {
+        use crate::prelude::NamedEasing;
+
+        let config = AnimationConfig::new(AnimationMode::Spring(Spring::wobbly()))
+            .with_loop(LoopMode::AlternateTimes(3))
+            .with_delay(Duration::from_millis(120))
+            .with_spring_solver(SpringSolver::Analytic)
+            .with_on_complete(|| {});
+        let json = serde_json::to_string(&config).expect("configs serialize");
+        let restored: AnimationConfig = serde_json::from_str(&json).expect("and deserialize");
+        assert_eq!(restored.mode, config.mode);
+        assert_eq!(restored.loop_mode, config.loop_mode);
+        assert_eq!(restored.delay, config.delay);
+        assert_eq!(restored.spring_solver, config.spring_solver);
+        assert!(restored.on_complete.is_none());
+
+        // Presets only need the fields they change
+        let preset: AnimationConfig =
+            serde_json::from_str(r#"{"mode": {"Tween": {"duration": {"secs": 0, "nanos": 400000000}, "easing": "EaseOutBack"}}}"#)
+                .expect("partial presets deserialize");
+        assert_eq!(
+            preset.mode,
+            AnimationMode::Tween(
+                Tween::new(Duration::from_millis(400)).with_easing(NamedEasing::EaseOutBack)
+            )
+        );
+        assert_eq!(preset.speed, 1.0);
+    }
+
+    #[test]
+    fn test_transform_parts_settle_on_their_own_springs() {
+        // Stepped by the test rather than the ticker
+        fn manual<T: Animatable>(value: T) -> Signal<Motion<T>> {
+            Signal::new(Motion {
+                manual: true,
+                ..Motion::new(value)
+            })
+        }
+
+        let dom = VirtualDom::new(|| rsx! {});
+        dom.in_runtime(|| {
+            ScopeId::ROOT.in_runtime(|| {
+                let start = TransformParts::from(Transform::identity());
+                let mut card = TransformMotion {
+                    translate: manual(start.translate),
+                    scale: manual(start.scale),
+                    rotation: manual(start.rotation),
+                    skew: manual(start.skew),
+                    rotation_3d: manual(start.rotation_3d),
+                };
+                let target = Transform::translate(100.0, 40.0).with_scale(2.0);
+                card.animate_to(
+                    target,
+                    TransformAnimationConfig::new(AnimationConfig::new(AnimationMode::Spring(
+                        Spring::slow(),
+                    )))
+                    .with_scale(AnimationConfig::new(AnimationMode::Spring(Spring::stiff()))),
+                );
+
+                let mut scale_settled = None;
+                let mut frames = 0;
+                while card.is_running() && frames < 2000 {
+                    card.translate.update(FRAME);
+                    card.scale.update(FRAME);
+                    card.rotation.update(FRAME);
+                    card.skew.update(FRAME);
+                    card.rotation_3d.update(FRAME);
+                    frames += 1;
+                    if !card.scale.is_running() {
+                        scale_settled.get_or_insert(frames);
+                    }
+                }
+
+                let scale_settled = scale_settled.unwrap_or(frames);
+                assert!(
+                    scale_settled < frames,
+                    "scale settled after {scale_settled} frames, translate after {frames}"
+                );
+                assert!(!card.is_running());
+                assert_eq!(card.get_value(), target);
+            })
+        });
+    }
+
+    #[test]
+    fn test_scroll_rubber_bands_and_snaps() {
+        let mut state = ScrollState {
+            snap_points: vec![0.0, 300.0, 600.0],
+            bounds: (0.0, 600.0),
+            dragged: None,
+        };
+        let mut offset = Motion::new(0.0f32);
+        let start = Time::now();
+        let at = |millis| start + Duration::from_millis(millis);
+
+        // Inside the bounds the offset follows the drag exactly
+        state.scroll_by(&mut offset, 100.0, at(0));
+        state.scroll_by(&mut offset, 30.0, at(100));
+        assert_eq!(offset.get_value(), 130.0);
+
+        // Moving at 300px/s carries it on towards the next page rather than the nearer
+        // one behind
+        state.release(&mut offset, at(110));
+        assert_eq!(offset.get_target(), 300.0);
+        while offset.update(FRAME) {}
+        assert_eq!(offset.get_value(), 300.0);
+
+        // Past the last bound the offset gives less than the drag, and springs back
+        state.scroll_by(&mut offset, 200.0, at(1000));
+        state.scroll_by(&mut offset, 200.0, at(2000));
+        let stretched = offset.get_value();
+        assert!(
+            stretched > 600.0 && stretched < 700.0,
+            "stretched {stretched}"
+        );
+        // Held still before letting go, so it springs straight back
+        state.release(&mut offset, at(2500));
+        offset.update(FRAME);
+        // Eases back from where it was stretched to rather than jumping in
+        let eased = stretched - offset.get_value();
+        assert!(eased > 0.0 && eased < 5.0, "eased {eased}");
+        while offset.update(FRAME) {}
+        assert_eq!(offset.get_value(), 600.0);
+    }
+
+    #[test]
+    fn test_manual_motion_is_left_to_the_caller() {
+        let dom = VirtualDom::new(|| rsx! {});
+        dom.in_runtime(|| {
+            ScopeId::ROOT.in_runtime(|| {
+                let mut motion = Signal::new(Motion {
+                    manual: true,
+                    ..Motion::new(0.0f32)
+                });
+
+                motion.animate_to(100.0, tween(100));
+                assert!(motion.is_running());
+                assert!(!motion.peek().scheduled.get());
+
+                // Only moves when stepped
+                assert_eq!(motion.get_value(), 0.0);
+                motion.update(0.05);
+                assert_eq!(motion.get_value(), 50.0);
+                while motion.is_running() {
+                    motion.update(FRAME);
+                }
+                assert_eq!(motion.get_value(), 100.0);
+            })
+        });
+    }
+
+    #[test]
+    fn test_motion_toggle_reverses_from_where_it_is() {
+        let dom = VirtualDom::new(|| rsx! {});
+        dom.in_runtime(|| {
+            ScopeId::ROOT.in_runtime(|| {
+                let mut toggle = MotionToggle {
+                    motion: Signal::new(Motion::new(0.0f32)),
+                    state: Signal::new(ToggleState {
+                        off: 0.0,
+                        on: 1.0,
+                        config: tween(100),
+                        is_on: false,
+                    }),
+                };
+                let mut motion = toggle.motion();
+
+                toggle.toggle();
+                assert!(toggle.is_on());
+                motion.update(0.05);
+                let halfway = toggle.get_value();
+                assert!(halfway > 0.0 && halfway < 1.0);
+
+                // Setting the same state again leaves the animation alone
+                toggle.set(true);
+                assert_eq!(motion.get_target(), 1.0);
+                assert_eq!(toggle.get_value(), halfway);
+
+                // Toggling back heads for off from where the value is
+                toggle.toggle();
+                assert!(!toggle.is_on());
+                assert_eq!(motion.get_target(), 0.0);
+                assert_eq!(toggle.get_value(), halfway);
+                while motion.update(FRAME) {}
+                assert_eq!(toggle.get_value(), 0.0);
+            })
+        });
+    }
+
+    #[test]
+    fn test_animate_presence_never_renders_content_hidden_on_mount() {
+        fn app(renders: Rc<Cell<usize>>) -> Element {
+            rsx! {
+                components::AnimatePresence {
+                    present: false,
+                    render: move |_| {
+                        renders.set(renders.get() + 1);
+                        rsx! {}
+                    },
+                }
+            }
+        }
+
+        let renders = Rc::new(Cell::new(0));
+        let mut dom = VirtualDom::new_with_props(app, renders.clone());
+        dom.rebuild_in_place();
+        for _ in 0..3 {
+            dom.process_events();
+            dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+        }
+        assert_eq!(renders.get(), 0);
+    }
+
+    #[test]
+    fn test_motion_map_reaps_keys_after_their_exit() {
+        let dom = VirtualDom::new(|| rsx! {});
+        dom.in_runtime(|| {
+            ScopeId::ROOT.in_runtime(|| {
+                let mut items = MotionMap::<&str, f32> {
+                    entries: Signal::new(Vec::new()),
+                };
+                let settle = |items: &MotionMap<&str, f32>, key| {
+                    if let Some(mut motion) = items.motion(&key) {
+                        while motion.update(FRAME) {}
+                    }
+                };
+
+                items.insert("a", 0.0, 1.0, tween(100));
+                items.insert("b", 0.0, 1.0, tween(100));
+                settle(&items, "a");
+                settle(&items, "b");
+                assert_eq!(items.entries(), vec![("a", 1.0, false), ("b", 1.0, false)]);
+
+                // Still listed while it animates out
+                items.remove(&"a", 0.0, tween(100));
+                assert_eq!(items.entries()[0], ("a", 1.0, true));
+                settle(&items, "a");
+                assert_eq!(items.entries(), vec![("b", 1.0, false)]);
+                assert_eq!(items.get(&"b"), Some(1.0));
+
+                // Reaped on the next change, so the key enters afresh
+                items.insert("a", 0.5, 1.0, tween(100));
+                assert_eq!(items.entries()[1], ("a", 0.5, false));
+
+                // Inserting an exiting key brings it back from where it is
+                items.remove(&"b", 0.0, tween(100));
+                if let Some(mut b) = items.motion(&"b") {
+                    b.update(0.05);
+                }
+                items.insert("b", 0.0, 1.0, tween(100));
+                let (_, value, exiting) = items.entries()[0];
+                assert!(value > 0.0 && value < 1.0 && !exiting);
+            })
+        });
+    }
+
+    #[test]
+    fn test_shift_by_carries_on_from_the_new_value() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(0.0, tween(100));
+        motion.shift_by(50.0);
+        motion.animate_to(0.0, tween(100));
+        assert_eq!(motion.get_value(), 50.0);
+
+        // Shifted halfway through a tween, it heads back from there without jumping
+        motion.update(0.05);
+        let before = motion.get_value();
+        motion.shift_by(20.0);
+        assert_eq!(motion.get_value(), before + 20.0);
+        motion.update(FRAME);
+        assert!(motion.get_value() < before + 20.0);
+        while motion.update(FRAME) {}
+        assert_eq!(motion.get_value(), 0.0);
+    }
+
+    #[test]
+    fn test_seek_spring_interpolates_linearly() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+        );
+        motion.update(FRAME);
+
+        motion.seek(0.25);
+        assert!(!motion.is_running());
+        assert_eq!(motion.get_value(), 25.0);
+        assert_eq!(motion.get_velocity(), 0.0);
+    }
+
+    #[test]
+    fn test_finish_jumps_to_target_and_ends_loops() {
+        let completions = Arc::new(Mutex::new(0));
+        let counter = completions.clone();
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            tween(1000)
+                .with_loop(LoopMode::Times(3))
+                .with_on_complete(move || {
+                    if let Ok(mut count) = counter.lock() {
+                        *count += 1;
+                    }
+                }),
+        );
+        motion.update(FRAME);
+
+        motion.finish();
+        assert!(!motion.is_running());
+        assert_eq!(motion.get_value(), 100.0);
+        assert_eq!(motion.current_loop(), 0);
+
+        // Finishing an idle motion doesn't complete it again
+        motion.finish();
+        assert_eq!(completions.lock().map(|c| *c).unwrap_or_default(), 1);
+    }
+
+    #[test]
+    fn test_finish_sequence_skips_to_last_step() {
+        let completed = Rc::new(Cell::new(false));
+        let done = completed.clone();
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(
+            AnimationSequence::new()
+                .then(10.0, tween(100))
+                .then(20.0, tween(100))
+                .then(30.0, tween(100))
+                .on_complete(move || done.set(true)),
+        );
+        motion.update(FRAME);
+
+        motion.finish();
+        assert!(!motion.is_running());
+        assert_eq!(motion.get_value(), 30.0);
+        assert!(completed.get());
+    }
+
+    #[test]
+    fn test_reduced_motion_skips_opted_in_animations() {
+        let reduced = || true;
+
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, tween(1000).with_respect_reduced_motion(true));
+        assert!(
+            !motion
+                .advance(FRAME, MotionConfig::default(), reduced)
+                .is_animating()
+        );
+        assert_eq!(motion.get_value(), 100.0);
+
+        // Animations that don't opt in play as usual
+        motion.animate_to(0.0, tween(1000));
+        assert!(
+            motion
+                .advance(FRAME, MotionConfig::default(), reduced)
+                .is_animating()
+        );
+        assert!(motion.get_value() > 0.0);
+    }
+
+    #[test]
+    fn test_on_complete_with_receives_final_value() {
+        let finished_at = Arc::new(Mutex::new(None));
+        let recorder = finished_at.clone();
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(42.0, tween(100));
+        motion.on_complete_with(move |value| {
+            if let Ok(mut finished_at) = recorder.lock() {
+                *finished_at = Some(value);
+            }
+        });
+        run_to_completion(&mut motion);
+        assert_eq!(
+            finished_at.lock().map(|v| *v).unwrap_or_default(),
+            Some(42.0)
+        );
+
+        // Replacing the animation drops the callback waiting on it
+        let fired = Rc::new(Cell::new(false));
+        let recorder = fired.clone();
+        motion.animate_to(0.0, tween(100));
+        motion.on_complete_with(move |_| recorder.set(true));
+        motion.animate_to(10.0, tween(100));
+        run_to_completion(&mut motion);
+        assert!(!fired.get());
+
+        // Nothing to wait for when idle
+        let idle_at = Rc::new(Cell::new(None));
+        let recorder = idle_at.clone();
+        motion.on_complete_with(move |value| recorder.set(Some(value)));
+        assert_eq!(idle_at.get(), Some(10.0));
+
+        let sequence_end = Rc::new(Cell::new(None));
+        let recorder = sequence_end.clone();
+        motion.animate_sequence(
+            AnimationSequence::new()
+                .then(10.0, tween(50))
+                .then(20.0, tween(50))
+                .on_complete_with(move |value| recorder.set(Some(value))),
+        );
+        run_to_completion(&mut motion);
+        assert_eq!(sequence_end.get(), Some(20.0));
+    }
+
+    #[test]
+    fn test_time_remaining() {
+        let mut motion = Motion::new(0.0f32);
+        assert_eq!(motion.time_remaining(), None);
+
+        // The unexpired delay counts towards the remaining time
+        motion.animate_to(1.0, tween(1000).with_delay(Duration::from_millis(500)));
+        assert_eq!(motion.time_remaining(), Some(Duration::from_millis(1500)));
+        motion.update(0.1);
+        let remaining = motion.time_remaining().unwrap_or_default().as_secs_f32();
+        assert!((remaining - 1.4).abs() < 1e-3);
+
+        // Loops still to play are included, and faster playback shortens the wait
+        motion.animate_to(
+            2.0,
+            tween(1000).with_loop(LoopMode::Times(3)).with_speed(2.0),
+        );
+        assert_eq!(motion.time_remaining(), Some(Duration::from_millis(1500)));
+
+        motion.animate_to(0.0, tween(1000).with_loop(LoopMode::Infinite));
+        assert_eq!(motion.time_remaining(), None);
+
+        let spring = Spring::default();
+        motion.animate_to(1.0, AnimationConfig::new(AnimationMode::Spring(spring)));
+        assert!(motion.time_remaining() <= Some(spring.estimated_settling_time()));
+        run_to_completion(&mut motion);
+        assert_eq!(motion.time_remaining(), None);
+    }
+
+    #[test]
+    fn test_repeat_delay_pauses_between_loops() {
+        const STEP: f32 = 0.01;
+        let looping = || tween(100).with_loop(LoopMode::Times(3));
+        let run = |config: AnimationConfig| {
+            let mut motion = Motion::new(0.0f32);
+            motion.animate_to(1.0, config);
+            let mut values = Vec::new();
+            while motion.update(STEP) {
+                values.push(motion.get_value());
+            }
+            assert_eq!(motion.get_value(), 1.0);
+            values
+        };
+
+        // Three 100ms iterations with a 50ms pause between each
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(1.0, looping().with_repeat_delay(Duration::from_millis(50)));
+        let remaining = motion.time_remaining().unwrap_or_default().as_secs_f32();
+        assert!((remaining - 0.4).abs() < 1e-3);
+
+        // Both pauses add to the time the loops take
+        let paused = run(looping().with_repeat_delay(Duration::from_millis(50)));
+        let back_to_back = run(looping());
+        assert_eq!(paused.len() - back_to_back.len(), 10);
+
+        // The value holds at the start of the next iteration while it waits
+        let restart = paused.iter().position(|&value| value == 0.0).unwrap_or(0);
+        assert!(restart > 0);
+        assert!(
+            paused[restart..restart + 5]
+                .iter()
+                .all(|&value| value == 0.0)
+        );
+        assert!(paused[restart + 6] > 0.0);
+    }
+
+    #[test]
+    fn test_is_delayed_only_while_holding() {
+        let mut motion = Motion::new(0.0f32);
+        assert!(!motion.is_delayed());
+        motion.animate_to(
+            1.0,
+            tween(100)
+                .with_delay(Duration::from_millis(50))
+                .with_loop(LoopMode::Times(2))
+                .with_repeat_delay(Duration::from_millis(50)),
+        );
+        assert!(motion.is_delayed());
+        motion.update(0.03);
+        assert!(motion.is_delayed());
+        assert_eq!(motion.get_value(), 0.0);
+
+        // The frame that ends the delay starts the motion
+        motion.update(0.03);
+        assert!(!motion.is_delayed());
+        assert!(motion.get_value() > 0.0);
+
+        // Holding again for the repeat delay once the first loop is done
+        while motion.current_loop() == 0 && motion.update(0.01) {}
+        assert!(motion.is_delayed());
+
+        motion.stop();
+        assert!(!motion.is_delayed());
+    }
+
+    #[test]
+    fn test_update_status_reports_each_phase() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            1.0,
+            tween(100)
+                .with_delay(Duration::from_millis(20))
+                .with_loop(LoopMode::Times(2)),
+        );
+
+        let mut statuses = Vec::new();
+        for _ in 0..100 {
+            let status = motion.update_status(0.01);
+            assert_eq!(status.is_animating(), motion.is_running());
+            if statuses.last() != Some(&status) {
+                statuses.push(status);
+            }
+        }
+        assert_eq!(
+            statuses,
+            vec![
+                AnimationFrameStatus::Delayed,
+                AnimationFrameStatus::Active,
+                AnimationFrameStatus::LoopedThisFrame,
+                AnimationFrameStatus::Active,
+                AnimationFrameStatus::CompletedThisFrame,
+                AnimationFrameStatus::Idle,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_spring_retarget_keeps_momentum() {
+        let spring = || AnimationConfig::new(AnimationMode::Spring(Spring::default()));
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, spring());
+        for _ in 0..5 {
+            motion.update(FRAME);
+        }
+        let velocity = motion.get_velocity();
+        assert!(velocity > 0.0);
+
+        motion.animate_to(200.0, spring());
+        assert_eq!(motion.get_velocity(), velocity);
+
+        motion.animate_to(300.0, spring().with_preserve_velocity_on_retarget(false));
+        assert_eq!(motion.get_velocity(), 0.0);
+    }
+
+    #[test]
+    fn test_spring_leads_a_moving_target() {
+        let spring = || {
+            AnimationConfig::new(AnimationMode::Spring(Spring::default()))
+                .with_lead_time(Duration::from_millis(100))
+        };
+        let mut motion = Motion::new(0.0f32);
+        let now = Instant::now();
+
+        // Nothing to estimate the target's velocity from yet
+        motion.try_animate_to_at(10.0, spring(), now);
+        assert_eq!(motion.target, 10.0);
+
+        // 10 units in 50ms is 200 units/s, so aim 100ms (20 units) ahead
+        motion.try_animate_to_at(20.0, spring(), now + Duration::from_millis(50));
+        assert_eq!(motion.target, 40.0);
+        assert_eq!(motion.get_target(), 20.0);
+
+        // Asked for the same place again, the target is standing still
+        motion.try_animate_to_at(20.0, spring(), now + Duration::from_millis(60));
+        assert_eq!(motion.target, 20.0);
+    }
+
+    #[test]
+    fn test_spring_lead_falls_back_once_target_stops() {
+        let spring = || {
+            AnimationConfig::new(AnimationMode::Spring(Spring::default()))
+                .with_lead_time(Duration::from_millis(100))
+        };
+        let mut motion = Motion::new(0.0f32);
+        let moved = Instant::now() - Duration::from_secs(1);
+        motion.try_animate_to_at(10.0, spring(), moved - Duration::from_millis(50));
+        motion.try_animate_to_at(20.0, spring(), moved);
+        assert_eq!(motion.target, 40.0);
+
+        // No retarget for longer than the velocity is trusted
+        motion.update(FRAME);
+        assert_eq!(motion.target, 20.0);
+        while motion.update(FRAME) {}
+        assert_eq!(motion.get_value(), 20.0);
+    }
+
+    #[test]
+    fn test_looping_spring_seeds_velocity() {
+        let spring = Spring {
+            velocity: 50.0,
+            ..Spring::stiff()
+        };
+        let config = AnimationConfig::new(AnimationMode::Spring(spring))
+            .with_loop(LoopMode::Times(2))
+            .with_loop_velocity(LoopVelocity::Seeded);
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(-10.0, config.clone());
+        assert_eq!(motion.get_velocity(), -50.0);
+
+        // The second loop restarts from the initial value with the same push
+        while motion.current_loop() == 0 && motion.update(FRAME) {}
+        assert_eq!(motion.current_loop(), 1);
+        assert_eq!(motion.get_value(), 0.0);
+        assert_eq!(motion.get_velocity(), -50.0);
+
+        // Without seeding, loops start from rest
+        motion.stop();
+        motion.animate_to(10.0, config.with_loop_velocity(LoopVelocity::Reset));
+        assert_eq!(motion.get_velocity(), 0.0);
+    }
+
+    #[test]
+    fn test_test_time_controls_drag_handoff() {
+        let spring = || AnimationConfig::new(AnimationMode::Spring(Spring::default()));
+        let frame = Duration::from_millis(16);
+        let mut motion = Motion::new(0.0f32).with_time_provider::<TestTime>();
+        // Steps frames on the test clock until the motion settles
+        let settle = |motion: &mut Motion<f32>| {
+            let mut peak = f32::MIN;
+            for _ in 0..1000 {
+                if !motion.is_running() {
+                    break;
+                }
+                let start = TestTime::now();
+                TestTime::advance(frame);
+                motion.update(TestTime::now().duration_since(start).as_secs_f32());
+                peak = peak.max(motion.get_value());
+            }
+            peak
+        };
+
+        // Released straight after the drag: the spring carries its momentum past the target
+        motion.set_value(0.0);
+        TestTime::advance(frame);
+        motion.set_value(8.0);
+        motion.animate_to(8.0, spring());
+        assert!((motion.get_velocity() - 500.0).abs() < 0.1);
+        assert!(settle(&mut motion) > 8.0);
+        assert!(!motion.is_running());
+        assert_eq!(motion.get_value(), 8.0);
+
+        // Held still past the drag timeout before release: nothing to hand over
+        motion.set_value(0.0);
+        TestTime::advance(frame);
+        motion.set_value(8.0);
+        TestTime::advance(DRAG_VELOCITY_TIMEOUT + frame);
+        motion.animate_to(8.0, spring());
+        assert_eq!(motion.get_velocity(), 0.0);
+    }
+
+    #[test]
+    fn test_drag_velocity_expires_after_release() {
+        let mut motion = Motion::new(0.0f32).with_time_provider::<TestTime>();
+        motion.set_value(0.0);
+        TestTime::advance(Duration::from_millis(16));
+        motion.set_value(8.0);
+        assert!((motion.get_velocity() - 500.0).abs() < 0.1);
+
+        // Let go without animating: the flick only counts for as long as it could be handed over
+        TestTime::advance(DRAG_VELOCITY_TIMEOUT);
+        assert!((motion.get_velocity() - 500.0).abs() < 0.1);
+        TestTime::advance(Duration::from_millis(1));
+        assert_eq!(motion.get_velocity(), 0.0);
+    }
+
+    #[test]
+    fn test_overlapping_step_takes_over_midway() {
+        let steps = Rc::new(RefCell::new(Vec::new()));
+        let completed = Rc::new(Cell::new(0));
+        let (steps_seen, completions) = (steps.clone(), completed.clone());
+
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(
+            AnimationSequence::new()
+                .then(100.0, tween(100))
+                .then_overlapping(0.0, tween(100), 0.5)
+                .on_step(move |index| steps_seen.borrow_mut().push(index))
+                .on_complete(move || completions.set(completions.get() + 1)),
+        );
+
+        let values = run_to_completion(&mut motion);
+        let peak = values.iter().copied().fold(f32::MIN, f32::max);
+        // Handed over halfway through the first step, so it never got near 100
+        assert!(peak > 40.0 && peak < 70.0, "peak {peak}");
+        assert_eq!(motion.get_value(), 0.0);
+        assert_eq!(*steps.borrow(), vec![0, 1]);
+        assert_eq!(completed.get(), 1);
+    }
+
+    #[test]
+    fn test_sequence_loops_as_a_whole() {
+        let steps = Rc::new(RefCell::new(Vec::new()));
+        let completed = Rc::new(Cell::new(0));
+        let (steps_seen, completions) = (steps.clone(), completed.clone());
+
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(
+            AnimationSequence::new()
+                .then(1.0, tween(50))
+                .then(0.5, tween(50))
+                .with_loop(LoopMode::Times(3))
+                .on_step(move |index| steps_seen.borrow_mut().push(index))
+                .on_complete(move || completions.set(completions.get() + 1)),
+        );
+
+        run_to_completion(&mut motion);
+        assert_eq!(*steps.borrow(), vec![0, 1, 0, 1, 0, 1]);
+        assert_eq!(completed.get(), 1);
+        assert_eq!(motion.get_value(), 0.5);
+    }
+
+    #[test]
+    fn test_relative_steps_follow_the_previous_target() {
+        let mut targets = Vec::new();
+        let mut motion = Motion::new(10.0f32);
+        motion.animate_sequence(
+            AnimationSequence::new()
+                .then_relative(5.0, tween(50))
+                .then(100.0, tween(50))
+                .then_relative(-20.0, tween(50))
+                .with_loop(LoopMode::Times(2)),
+        );
+        for _ in 0..2000 {
+            if !motion.is_running() {
+                break;
+            }
+            motion.update(FRAME);
+            if targets.last() != Some(&motion.target) {
+                targets.push(motion.target);
+            }
+        }
+        // The second pass starts from the last step's target, 80
+        assert_eq!(targets, vec![15.0, 100.0, 80.0, 85.0, 100.0, 80.0]);
+        assert_eq!(motion.get_value(), 80.0);
+
+        // Finishing early resolves the steps that never ran
+        motion.animate_sequence(
+            AnimationSequence::new()
+                .then_relative(10.0, tween(50))
+                .then_relative(10.0, tween(50)),
+        );
+        motion.finish();
+        assert_eq!(motion.get_value(), 100.0);
+    }
+
+    #[test]
+    fn test_shake_and_wobble_end_back_at_the_origin() {
+        let mut motion = Motion::new(10.0f32);
+        motion.animate_sequence(AnimationSequence::shake(8.0, 5));
+        let values = run_to_completion(&mut motion);
+        let peak = values.iter().copied().fold(f32::MIN, f32::max);
+        let trough = values.iter().copied().fold(f32::MAX, f32::min);
+        // The first swing goes the full intensity, the second most of it the other way
+        assert!((peak - 18.0).abs() < 0.01, "peak {peak}");
+        assert!(trough < 5.0, "trough {trough}");
+        assert!((motion.get_value() - 10.0).abs() < 1e-4);
+
+        let origin = Vector2D::new(3.0, -4.0);
+        let mut motion = Motion::new(origin);
+        motion.animate_sequence(AnimationSequence::wobble(Vector2D::new(10.0, 5.0), 4));
+        for _ in 0..2000 {
+            if !motion.is_running() {
+                break;
+            }
+            motion.update(FRAME);
+        }
+        assert!(!motion.is_running());
+        assert!(motion.get_value().sub(&origin).magnitude() < 1e-4);
+    }
+
+    #[test]
+    fn test_sequence_loops_forever() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(
+            AnimationSequence::new()
+                .then(1.0, tween(50))
+                .then(0.0, tween(50))
+                .with_loop(LoopMode::Infinite),
+        );
+
+        run_to_completion(&mut motion);
+        assert!(motion.is_running());
+    }
+
+    #[test]
+    fn test_ping_pong_swaps_direction_each_leg() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            AnimationConfig::new(AnimationMode::Tween(Tween::new(Duration::from_millis(100))))
+                .with_loop(LoopMode::PingPong),
+        );
+
+        assert!(motion.handle_completion());
+        assert_eq!((motion.initial, motion.target), (100.0, 0.0));
+        assert!(motion.handle_completion());
+        assert_eq!((motion.initial, motion.target), (0.0, 100.0));
+        assert!(motion.is_running());
+    }
+
+    #[test]
+    fn test_on_loop_fires_at_each_restart() {
+        let loops = Arc::new(Mutex::new(Vec::new()));
+        let completions = Arc::new(Mutex::new(0));
+        let (seen, counter) = (loops.clone(), completions.clone());
+
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            tween(100)
+                .with_loop(LoopMode::Times(3))
+                .with_on_loop(move |index| {
+                    if let Ok(mut loops) = seen.lock() {
+                        loops.push(index);
+                    }
+                })
+                .with_on_complete(move || {
+                    if let Ok(mut count) = counter.lock() {
+                        *count += 1;
+                    }
+                }),
+        );
+
+        run_to_completion(&mut motion);
+        // Two restarts for three iterations, and the end isn't one of them
+        assert_eq!(
+            loops.lock().map(|v| v.clone()).unwrap_or_default(),
+            vec![1, 2]
+        );
+        assert_eq!(completions.lock().map(|c| *c).unwrap_or_default(), 1);
+    }
+
+    #[test]
+    fn test_events_follow_the_lifecycle() {
+        let mut motion = Motion::new(0.0f32);
+        let mut events = motion.events();
+        let mut drain = || {
+            let mut seen = Vec::new();
+            while let Ok(event) = events.try_recv() {
+                seen.push(event);
+            }
+            seen
+        };
+
+        motion.animate_to(100.0, tween(100).with_loop(LoopMode::Times(2)));
+        run_to_completion(&mut motion);
+        assert_eq!(
+            drain(),
+            vec![
+                AnimationEvent::Started,
+                AnimationEvent::Looped(1),
+                AnimationEvent::Completed
+            ]
+        );
+
+        // Replaced midway, then stopped
+        motion.animate_to(0.0, tween(100));
+        motion.update(FRAME);
+        motion.animate_to(50.0, tween(100));
+        motion.stop();
+        motion.stop();
+        assert_eq!(
+            drain(),
+            vec![
+                AnimationEvent::Started,
+                AnimationEvent::Interrupted,
+                AnimationEvent::Started,
+                AnimationEvent::Interrupted
+            ]
+        );
+
+        // A sequence completes once, after its last step
+        motion.animate_sequence(
+            AnimationSequence::new()
+                .then(10.0, tween(50))
+                .then(20.0, tween(50)),
+        );
+        run_to_completion(&mut motion);
+        assert_eq!(
+            drain(),
+            vec![AnimationEvent::Started, AnimationEvent::Completed]
+        );
     }
 }
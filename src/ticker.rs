@@ -0,0 +1,229 @@
+//! Shared frame driver for motion values
+//!
+//! Instead of every `use_motion` spawning its own timer loop, running motions register
+//! with one ticker per thread. The ticker steps them all with the same `dt` each frame
+//! and shuts down once nothing is animating, so idle motions cost nothing. On web, frames
+//! come from `requestAnimationFrame` and no callback is scheduled while the ticker is idle.
+
+use std::cell::RefCell;
+
+use dioxus::prelude::*;
+
+use crate::animations::utils::Animatable;
+use crate::{Duration, Motion, Time, TimeProvider};
+use instant::Instant;
+
+/// Steps one registered motion, returning whether it should stay registered
+type Tick = Box<dyn FnMut(f32) -> bool>;
+
+/// Time between frames where there's no display to sync to: native targets, and web
+/// contexts without a `window` such as workers
+pub(crate) const FRAME_INTERVAL: Duration = Duration::from_micros(8333); // ~120fps
+
+/// Largest step handed to motions, so a stalled tab doesn't teleport values
+const MAX_DT: f32 = 0.1;
+
+#[derive(Default)]
+struct Ticker {
+    motions: Vec<Tick>,
+    driving: bool,
+}
+
+thread_local! {
+    static TICKER: RefCell<Ticker> = RefCell::new(Ticker::default());
+}
+
+/// Applies `change` to the motion and makes sure the shared ticker drives it if it's running
+pub(crate) fn drive<T: Animatable>(
+    mut motion: Signal<Motion<T>>,
+    change: impl FnOnce(&mut Motion<T>),
+) {
+    let needs_registration = {
+        let mut state = motion.write();
+        change(&mut state);
+        state.is_running() && !state.paused && !state.manual && !state.scheduled.replace(true)
+    };
+    if needs_registration {
+        register(motion);
+    }
+}
+
+fn register<T: Animatable>(mut motion: Signal<Motion<T>>) {
+    let tick: Tick = Box::new(move |dt| {
+        // The owning component may have unmounted since the last frame
+        let Ok(stepping) = motion
+            .try_peek()
+            .map(|state| state.is_running() && !state.paused)
+        else {
+            return false;
+        };
+        if stepping {
+            // Borrowed elsewhere this frame, so try again on the next one
+            let Ok(mut state) = motion.try_write() else {
+                return true;
+            };
+            state.update(dt);
+            if state.is_running() && !state.paused {
+                return true;
+            }
+        }
+        // Finished or paused: `drive` registers it again when it next needs frames
+        if let Ok(state) = motion.try_peek() {
+            state.scheduled.set(false);
+        }
+        false
+    });
+
+    let start_driving = TICKER.with(|ticker| {
+        let mut ticker = ticker.borrow_mut();
+        ticker.motions.push(tick);
+        !std::mem::replace(&mut ticker.driving, true)
+    });
+    if start_driving {
+        dioxus::core::spawn_forever(run());
+    }
+}
+
+/// Resolves on the next animation frame, with the frame's timestamp when the platform has one
+///
+/// On web this is the browser's next `requestAnimationFrame` callback, so motions step
+/// exactly once per painted frame at the display's refresh rate and pause with the tab.
+/// The timestamp is the callback's `DOMHighResTimeStamp` in milliseconds, the time the
+/// frame started rather than when this future got polled.
+#[cfg(feature = "web")]
+pub(crate) async fn next_frame() -> Option<f64> {
+    use wasm_bindgen::prelude::*;
+
+    let Some(window) = web_sys::window() else {
+        Time::delay(FRAME_INTERVAL).await;
+        return None;
+    };
+    let (sender, receiver) = futures_channel::oneshot::channel::<f64>();
+    // Frees itself once called, so nothing leaks and dropping this future is safe
+    let cb = Closure::once_into_js(move |timestamp: f64| {
+        let _ = sender.send(timestamp);
+    });
+    if window.request_animation_frame(cb.unchecked_ref()).is_err() {
+        Time::delay(FRAME_INTERVAL).await;
+        return None;
+    }
+    receiver.await.ok()
+}
+
+/// Resolves on the next animation frame, with the frame's timestamp when the platform has one
+#[cfg(not(feature = "web"))]
+pub(crate) async fn next_frame() -> Option<f64> {
+    Time::delay(FRAME_INTERVAL).await;
+    None
+}
+
+/// Measures the time between ticker frames
+///
+/// Prefers the difference between frame timestamps, which is exactly one refresh interval
+/// however late the ticker task was woken, and falls back to the clock when either frame
+/// came without one.
+struct FrameClock {
+    last_instant: Instant,
+    last_timestamp: Option<f64>,
+}
+
+impl FrameClock {
+    fn new() -> Self {
+        Self {
+            last_instant: Time::now(),
+            last_timestamp: None,
+        }
+    }
+
+    /// Seconds since the previous frame, given this frame's timestamp in milliseconds
+    fn tick(&mut self, timestamp: Option<f64>) -> f32 {
+        let now = Time::now();
+        let dt = match (self.last_timestamp, timestamp) {
+            (Some(last), Some(current)) => ((current - last) / 1000.0).max(0.0) as f32,
+            _ => now.duration_since(self.last_instant).as_secs_f32(),
+        };
+        self.last_instant = now;
+        self.last_timestamp = timestamp;
+        dt
+    }
+}
+
+async fn run() {
+    let mut clock = FrameClock::new();
+    loop {
+        let timestamp = next_frame().await;
+        let dt = clock.tick(timestamp).min(MAX_DT);
+
+        // Step outside the borrow so callbacks can start other motions
+        let mut motions = TICKER.with(|ticker| std::mem::take(&mut ticker.borrow_mut().motions));
+        motions.retain_mut(|tick| tick(dt));
+
+        let keep_driving = TICKER.with(|ticker| {
+            let mut ticker = ticker.borrow_mut();
+            motions.append(&mut ticker.motions);
+            ticker.motions = motions;
+            ticker.driving = !ticker.motions.is_empty();
+            ticker.driving
+        });
+        if !keep_driving {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn take_ticks() -> Vec<Tick> {
+        TICKER.with(|ticker| std::mem::take(&mut ticker.borrow_mut().motions))
+    }
+
+    #[test]
+    fn test_borrowed_motion_stays_registered() {
+        let dom = VirtualDom::new(|| rsx! {});
+        dom.in_runtime(|| {
+            ScopeId::ROOT.in_runtime(|| {
+                let motion = Signal::new(Motion::new(0.0f32));
+                drive(motion, |state| {
+                    state.animate_to(100.0, crate::AnimationConfig::default());
+                });
+                let mut ticks = take_ticks();
+                assert_eq!(ticks.len(), 1);
+
+                let held = motion.read();
+                assert!(ticks[0](0.016));
+                drop(held);
+                assert!(ticks[0](0.016));
+                assert!(motion.peek().current > 0.0);
+            })
+        });
+    }
+
+    #[test]
+    fn test_paused_motion_leaves_the_ticker_until_resumed() {
+        let dom = VirtualDom::new(|| rsx! {});
+        dom.in_runtime(|| {
+            ScopeId::ROOT.in_runtime(|| {
+                let mut motion = Signal::new(Motion::new(0.0f32));
+                drive(motion, |state| {
+                    state.animate_to(100.0, crate::AnimationConfig::default());
+                });
+                let mut ticks = take_ticks();
+
+                motion.write().pause();
+                assert!(!ticks[0](0.016));
+                assert!(!motion.peek().scheduled.get());
+
+                // Changes while paused don't bring it back
+                drive(motion, |state| state.retarget(50.0));
+                assert!(take_ticks().is_empty());
+
+                drive(motion, |state| state.resume());
+                let mut ticks = take_ticks();
+                assert_eq!(ticks.len(), 1);
+                assert!(ticks[0](0.016));
+            })
+        });
+    }
+}
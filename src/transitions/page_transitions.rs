@@ -10,6 +10,16 @@ use crate::{
 
 use super::utils::TransitionVariant;
 
+/// Spring used for route transitions when `AnimatedOutlet` isn't given a `transition_mode`
+///
+/// A weighty, well-damped spring with a small initial push, so pages glide in and barely overshoot.
+pub const DEFAULT_TRANSITION_SPRING: Spring = Spring {
+    stiffness: 160.0,
+    damping: 25.0,
+    mass: 1.5,
+    velocity: 10.0,
+};
+
 #[derive(Clone)]
 pub enum AnimatedRouterContext<R: Routable + PartialEq> {
     /// Transition from one route to another.
@@ -53,8 +63,18 @@ impl<R: Routable + PartialEq> AnimatedRouterContext<R> {
 /// determine when an animated transition should occur. When a transition is detected and
 /// the layout depth or route conditions are met, it renders a transition component; otherwise,
 /// it renders a standard outlet.
+///
+/// Navigating to a shallower layout depth plays the transition of the route being left
+/// backwards, so leaving a nested layout mirrors how it was entered.
+///
+/// `transition_mode` sets the spring or tween that drives every route transition, falling back
+/// to [`DEFAULT_TRANSITION_SPRING`]. `on_settled` is called with the destination route once its
+/// transition has finished and only that route is left rendering.
 /// ```
-pub fn AnimatedOutlet<R: AnimatableRoute>() -> Element {
+pub fn AnimatedOutlet<R: AnimatableRoute>(
+    transition_mode: Option<AnimationMode>,
+    on_settled: Option<EventHandler<R>>,
+) -> Element {
     let route = use_route::<R>();
     // Create router context only if we're the root AnimatedOutlet
     let mut prev_route = use_signal(|| AnimatedRouterContext::In(route.clone()));
@@ -92,11 +112,14 @@ pub fn AnimatedOutlet<R: AnimatableRoute>() -> Element {
         // If we're transitioning from/to root, or the outlet is at the same depth,
         // render the animated transition between routes
         if involves_root || is_same_depth_and_matching_level {
+            let mode = transition_mode.unwrap_or(AnimationMode::Spring(DEFAULT_TRANSITION_SPRING));
             return rsx! {
                 FromRouteToCurrent::<R> {
                     route_type: PhantomData,
                     from: from.clone(),
                     to: to.clone(),
+                    mode,
+                    on_settled,
                 }
             };
         } else {
@@ -123,37 +146,45 @@ pub fn use_animated_router<Route: Routable + PartialEq>() -> Signal<AnimatedRout
 }
 
 #[component]
-fn FromRouteToCurrent<R: AnimatableRoute>(route_type: PhantomData<R>, from: R, to: R) -> Element {
+fn FromRouteToCurrent<R: AnimatableRoute>(
+    route_type: PhantomData<R>,
+    from: R,
+    to: R,
+    mode: AnimationMode,
+    on_settled: Option<EventHandler<R>>,
+) -> Element {
     let mut animated_router = use_animated_router::<R>();
-    let config = to.get_transition().get_config();
+    let settled_route = to.clone();
+    // Going deeper plays the new route's transition; coming back out to a shallower layout
+    // plays the transition of the route being left in reverse, so pages retrace their path
+    let config = if to.get_layout_depth() < from.get_layout_depth() {
+        from.get_transition().get_config().reversed()
+    } else {
+        to.get_transition().get_config()
+    };
     let mut from_transform = use_motion(config.exit_start);
     let mut to_transform = use_motion(config.enter_start);
     let mut from_opacity = use_motion(1.0f32);
     let mut to_opacity = use_motion(0.0f32);
+    let mut from_blur = use_motion(0.0f32);
+    let mut to_blur = use_motion(config.enter_blur);
 
     use_effect(move || {
-        let spring = Spring {
-            stiffness: 160.0, // Reduced from 180.0 for less aggressive movement
-            damping: 25.0,    // Increased from 12.0 for faster settling
-            mass: 1.5,        // Slightly increased for more "weight"
-            velocity: 10.0,   // Keep at 0 for predictable start
-        };
+        let animation = AnimationConfig::new(mode.clone());
 
         // Animate FROM route
-        from_transform.animate_to(
-            config.exit_end,
-            AnimationConfig::new(AnimationMode::Spring(spring)),
-        );
+        from_transform.animate_to(config.exit_end, animation.clone());
 
         // Animate TO route
-        to_transform.animate_to(
-            config.enter_end,
-            AnimationConfig::new(AnimationMode::Spring(spring)),
-        );
+        to_transform.animate_to(config.enter_end, animation.clone());
 
         // Fade out old route
-        from_opacity.animate_to(0.0, AnimationConfig::new(AnimationMode::Spring(spring)));
-        to_opacity.animate_to(1.0, AnimationConfig::new(AnimationMode::Spring(spring)));
+        from_opacity.animate_to(0.0, animation.clone());
+        to_opacity.animate_to(1.0, animation.clone());
+
+        // Blur out the old route and bring the new one into focus
+        from_blur.animate_to(config.exit_blur, animation.clone());
+        to_blur.animate_to(0.0, animation);
     });
 
     use_effect(move || {
@@ -161,11 +192,27 @@ fn FromRouteToCurrent<R: AnimatableRoute>(route_type: PhantomData<R>, from: R, t
             && !to_transform.is_running()
             && !from_opacity.is_running()
             && !to_opacity.is_running()
+            && !from_blur.is_running()
+            && !to_blur.is_running()
         {
-            animated_router.write().settle();
+            // Only the first pass after the animations stop actually settles the router
+            let in_transition =
+                matches!(*animated_router.peek(), AnimatedRouterContext::FromTo(..));
+            if in_transition {
+                animated_router.write().settle();
+                if let Some(on_settled) = on_settled {
+                    on_settled.call(settled_route.clone());
+                }
+            }
         }
     });
 
+    let from_filter = blur_filter(from_blur.get_value());
+    let to_filter = blur_filter(to_blur.get_value());
+    // Easings that overshoot would otherwise take a shrinking page past zero and mirror it
+    let from_scale = from_transform.get_value().scale.max(0.0);
+    let to_scale = to_transform.get_value().scale.max(0.0);
+
     rsx! {
         div {
             class: "route-container",
@@ -174,8 +221,9 @@ fn FromRouteToCurrent<R: AnimatableRoute>(route_type: PhantomData<R>, from: R, t
                 class: "route-content from",
                 style: "
                     transform: translate3d({from_transform.get_value().x}%, {from_transform.get_value().y}%, 0)
-                             scale({from_transform.get_value().scale});
+                             scale({from_scale});
                     opacity: {from_opacity.get_value()};
+                    {from_filter}
                     will-change: transform, opacity;
                        backface-visibility: hidden;
                     -webkit-backface-visibility: hidden;
@@ -186,8 +234,9 @@ fn FromRouteToCurrent<R: AnimatableRoute>(route_type: PhantomData<R>, from: R, t
                 class: "route-content to",
                 style: "
                     transform: translate3d({to_transform.get_value().x}%, {to_transform.get_value().y}%, 0)
-                             scale({to_transform.get_value().scale});
+                             scale({to_scale});
                     opacity: {to_opacity.get_value()};
+                    {to_filter}
                     will-change: transform, opacity;
                     backface-visibility: hidden;
                     -webkit-backface-visibility: hidden;
@@ -197,3 +246,13 @@ fn FromRouteToCurrent<R: AnimatableRoute>(route_type: PhantomData<R>, from: R, t
         }
     }
 }
+
+/// CSS `filter` declaration for a blur, left out entirely once the page is sharp since any
+/// filter makes the page the containing block of its fixed-position children
+fn blur_filter(pixels: f32) -> String {
+    if pixels > 0.0 {
+        format!("filter: blur({pixels}px);")
+    } else {
+        String::new()
+    }
+}
@@ -1,6 +1,6 @@
 use crate::prelude::Transform;
 
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct TransitionConfig {
     // For the page that's leaving (FROM)
     pub exit_start: Transform, // Starting position of exiting page
@@ -9,14 +9,66 @@ pub struct TransitionConfig {
     // For the page that's entering (TO)
     pub enter_start: Transform, // Starting position of entering page
     pub enter_end: Transform,   // Final position of entering page
+
+    // Blur in pixels; pages always rest unblurred
+    pub exit_blur: f32,  // Blur the exiting page ends at
+    pub enter_blur: f32, // Blur the entering page starts from
+}
+
+impl TransitionConfig {
+    /// The same transition played backwards, for navigating back out of it
+    ///
+    /// The entering page comes in from where the exiting page would have gone, and the
+    /// exiting page leaves the way the entering page would have arrived.
+    pub fn reversed(&self) -> Self {
+        Self {
+            exit_start: self.enter_end,
+            exit_end: self.enter_start,
+            enter_start: self.exit_end,
+            enter_end: self.exit_start,
+            exit_blur: self.enter_blur,
+            enter_blur: self.exit_blur,
+        }
+    }
 }
 
+/// Direction a page moves in during a [`TransitionVariant::Slide`]
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl Direction {
+    /// Translation, in percent, of a page that has moved `distance` towards this direction
+    fn offset(self, distance: f32) -> (f32, f32) {
+        match self {
+            Direction::Left => (-distance, 0.0),
+            Direction::Right => (distance, 0.0),
+            Direction::Up => (0.0, -distance),
+            Direction::Down => (0.0, distance),
+        }
+    }
+}
+
+/// Page transition played when navigating to a route
+///
+/// Unit variants are also listed in the `MotionTransitions` derive, which checks
+/// `#[transition(...)]` names against them; keep the two in sync.
 #[derive(PartialEq, Clone)]
 pub enum TransitionVariant {
     SlideLeft,
     SlideRight,
     SlideUp,
     SlideDown,
+    /// Slide towards `direction`, moving pages by `distance_percent` of their size
+    /// instead of the full 100% used by the fixed slide variants
+    Slide {
+        direction: Direction,
+        distance_percent: f32,
+    },
     Fade,
     // Scale transitions
     ScaleUp,
@@ -42,9 +94,15 @@ pub enum TransitionVariant {
     FlipHorizontalFade,
     FlipVerticalFade,
 
+    /// Modal entrance: the new page fades in while coming into focus from an 8px blur
+    Dialog,
+
     // Zoom transitions
     ZoomIn,
     ZoomOut,
+    /// Depth effect: the new page grows in from slightly smaller while the old one
+    /// grows past full size, both cross-fading
+    Zoom,
 
     // Diagonal slides
     SlideDiagonalUpLeft,
@@ -84,6 +142,7 @@ impl TransitionVariant {
                     exit_end: Transform::new(-100.0, 0.0, 1.0, 0.0),   // Exit left
                     enter_start: Transform::new(100.0, 0.0, 1.0, 0.0), // Enter from right
                     enter_end: identity,                               // End in place
+                    ..Default::default()
                 }
             }
 
@@ -93,6 +152,7 @@ impl TransitionVariant {
                     exit_end: Transform::new(100.0, 0.0, 1.0, 0.0),     // Exit right
                     enter_start: Transform::new(-100.0, 0.0, 1.0, 0.0), // Enter from left
                     enter_end: identity,                                // End in place
+                    ..Default::default()
                 }
             }
 
@@ -102,6 +162,7 @@ impl TransitionVariant {
                     exit_end: Transform::new(0.0, -100.0, 1.0, 0.0),   // Exit up
                     enter_start: Transform::new(0.0, 100.0, 1.0, 0.0), // Enter from bottom
                     enter_end: identity,                               // End in place
+                    ..Default::default()
                 }
             }
 
@@ -111,6 +172,21 @@ impl TransitionVariant {
                     exit_end: Transform::new(0.0, 100.0, 1.0, 0.0),     // Exit down
                     enter_start: Transform::new(0.0, -100.0, 1.0, 0.0), // Enter from top
                     enter_end: identity,                                // End in place
+                    ..Default::default()
+                }
+            }
+
+            TransitionVariant::Slide {
+                direction,
+                distance_percent,
+            } => {
+                let (x, y) = direction.offset(*distance_percent);
+                TransitionConfig {
+                    exit_start: identity,                          // Start in place
+                    exit_end: Transform::new(x, y, 1.0, 0.0),      // Exit towards the direction
+                    enter_start: Transform::new(-x, -y, 1.0, 0.0), // Enter from the opposite side
+                    enter_end: identity,                           // End in place
+                    ..Default::default()
                 }
             }
 
@@ -119,210 +195,260 @@ impl TransitionVariant {
                 exit_end: Transform::new(0.0, 0.0, 1.0, 0.0),    // Fade out completely
                 enter_start: Transform::new(0.0, 0.0, 1.0, 0.0), // Start invisible
                 enter_end: identity,                             // Fade in completely
+                ..Default::default()
             },
             TransitionVariant::ScaleUp => TransitionConfig {
                 exit_start: identity,                            // Start in place
                 exit_end: Transform::new(0.0, 0.0, 0.0, 0.0),    // Shrink to nothing
                 enter_start: Transform::new(0.0, 0.0, 0.0, 0.0), // Start as nothing
                 enter_end: identity,                             // Grow to full size
+                ..Default::default()
             },
             TransitionVariant::ScaleDown => TransitionConfig {
                 exit_start: identity,                            // Start in place
                 exit_end: Transform::new(0.0, 0.0, 2.0, 0.0),    // Grow to twice size
                 enter_start: Transform::new(0.0, 0.0, 2.0, 0.0), // Start twice size
                 enter_end: identity,                             // Shrink to full size
+                ..Default::default()
             },
             TransitionVariant::FlipHorizontal => TransitionConfig {
                 exit_start: identity,                               // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 180.0),     // Flip 180 degrees horizontally
                 enter_start: Transform::new(0.0, 0.0, 1.0, -180.0), // Start flipped 180 degrees horizontally
                 enter_end: identity,                                // End in place
+                ..Default::default()
             },
             TransitionVariant::FlipVertical => TransitionConfig {
                 exit_start: identity,                               // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 180.0),     // Flip 180 degrees vertically
                 enter_start: Transform::new(0.0, 0.0, 1.0, -180.0), // Start flipped 180 degrees vertically
                 enter_end: identity,                                // End in place
+                ..Default::default()
             },
             TransitionVariant::RotateLeft => TransitionConfig {
                 exit_start: identity,                              // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 90.0),     // Rotate 90 degrees to the left
                 enter_start: Transform::new(0.0, 0.0, 1.0, -90.0), // Start rotated 90 degrees to the right
                 enter_end: identity,                               // End in place
+                ..Default::default()
             },
             TransitionVariant::RotateRight => TransitionConfig {
                 exit_start: identity,                             // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, -90.0),   // Rotate 90 degrees to the right
                 enter_start: Transform::new(0.0, 0.0, 1.0, 90.0), // Start rotated 90 degrees to the left
                 enter_end: identity,                              // End in place
+                ..Default::default()
             },
             TransitionVariant::SlideUpFade => TransitionConfig {
                 exit_start: identity,                              // Start in place
                 exit_end: Transform::new(0.0, -100.0, 1.0, 0.0),   // Exit up
                 enter_start: Transform::new(0.0, 100.0, 1.0, 0.0), // Enter from bottom
                 enter_end: identity,                               // End in place
+                ..Default::default()
             },
             TransitionVariant::SlideDownFade => TransitionConfig {
                 exit_start: identity,                               // Start in place
                 exit_end: Transform::new(0.0, 100.0, 1.0, 0.0),     // Exit down
                 enter_start: Transform::new(0.0, -100.0, 1.0, 0.0), // Enter from top
                 enter_end: identity,                                // End in place
+                ..Default::default()
             },
             TransitionVariant::ScaleUpFade => TransitionConfig {
                 exit_start: identity,                            // Start in place
                 exit_end: Transform::new(0.0, 0.0, 0.0, 0.0),    // Shrink to nothing
                 enter_start: Transform::new(0.0, 0.0, 0.0, 0.0), // Start as nothing
                 enter_end: identity,                             // Grow to full size
+                ..Default::default()
             },
             TransitionVariant::BounceIn => TransitionConfig {
                 exit_start: identity,                              // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 0.0),      // No change
                 enter_start: Transform::new(0.0, 100.0, 1.0, 0.0), // Start from bottom
                 enter_end: identity,                               // End in place
+                ..Default::default()
             },
             TransitionVariant::BounceOut => TransitionConfig {
                 exit_start: identity,                            // Start in place
                 exit_end: Transform::new(0.0, 100.0, 1.0, 0.0),  // Exit to bottom
                 enter_start: Transform::new(0.0, 0.0, 1.0, 0.0), // Start in place
                 enter_end: identity,                             // No change
+                ..Default::default()
             },
             TransitionVariant::ScaleDownFade => TransitionConfig {
                 exit_start: identity,                            // Start in place
                 exit_end: Transform::new(0.0, 0.0, 2.0, 0.0),    // Grow to twice size
                 enter_start: Transform::new(0.0, 0.0, 2.0, 0.0), // Start twice size
                 enter_end: identity,                             // Shrink to full size
+                ..Default::default()
             },
             TransitionVariant::RotateLeftFade => TransitionConfig {
                 exit_start: identity,                              // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 90.0),     // Rotate 90 degrees to the left
                 enter_start: Transform::new(0.0, 0.0, 1.0, -90.0), // Start rotated 90 degrees to the right
                 enter_end: identity,                               // End in place
+                ..Default::default()
             },
             TransitionVariant::RotateRightFade => TransitionConfig {
                 exit_start: identity,                             // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, -90.0),   // Rotate 90 degrees to the right
                 enter_start: Transform::new(0.0, 0.0, 1.0, 90.0), // Start rotated 90 degrees to the left
                 enter_end: identity,                              // End in place
+                ..Default::default()
             },
             TransitionVariant::FlipHorizontalFade => TransitionConfig {
                 exit_start: identity,                               // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 180.0),     // Flip 180 degrees horizontally
                 enter_start: Transform::new(0.0, 0.0, 1.0, -180.0), // Start flipped 180 degrees horizontally
                 enter_end: identity,                                // End in place
+                ..Default::default()
             },
             TransitionVariant::FlipVerticalFade => TransitionConfig {
                 exit_start: identity,                               // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 180.0),     // Flip 180 degrees vertically
                 enter_start: Transform::new(0.0, 0.0, 1.0, -180.0), // Start flipped 180 degrees vertically
                 enter_end: identity,                                // End in place
+                ..Default::default()
+            },
+            TransitionVariant::Dialog => TransitionConfig {
+                exit_start: identity,  // Start in place
+                exit_end: identity,    // Stay in place while fading out
+                enter_start: identity, // Start in place
+                enter_end: identity,   // Stay in place while fading in
+                exit_blur: 0.0,        // Stay sharp
+                enter_blur: 8.0,       // Start blurred and sharpen
             },
             TransitionVariant::ZoomIn => TransitionConfig {
                 exit_start: identity,                            // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 0.0),    // No change
                 enter_start: Transform::new(0.0, 0.0, 0.0, 0.0), // Start as nothing
                 enter_end: identity,                             // Grow to full size
+                ..Default::default()
             },
             TransitionVariant::ZoomOut => TransitionConfig {
                 exit_start: identity,                          // Start in place
                 exit_end: Transform::new(0.0, 0.0, 2.0, 0.0),  // Grow to twice size
                 enter_start: identity,                         // Start in place
                 enter_end: Transform::new(0.0, 0.0, 0.0, 0.0), // Shrink to full size
+                ..Default::default()
+            },
+            TransitionVariant::Zoom => TransitionConfig {
+                exit_start: identity,                            // Start in place
+                exit_end: Transform::new(0.0, 0.0, 1.1, 0.0),    // Grow slightly past full size
+                enter_start: Transform::new(0.0, 0.0, 0.8, 0.0), // Start slightly smaller
+                enter_end: identity,                             // Grow to full size
+                ..Default::default()
             },
             TransitionVariant::SlideDiagonalUpLeft => TransitionConfig {
                 exit_start: identity,                                // Start in place
                 exit_end: Transform::new(-100.0, -100.0, 1.0, 0.0),  // Exit up and left
                 enter_start: Transform::new(100.0, 100.0, 1.0, 0.0), // Enter from bottom right
                 enter_end: identity,                                 // End in place
+                ..Default::default()
             },
             TransitionVariant::SlideDiagonalUpRight => TransitionConfig {
                 exit_start: identity,                                 // Start in place
                 exit_end: Transform::new(100.0, -100.0, 1.0, 0.0),    // Exit up and right
                 enter_start: Transform::new(-100.0, 100.0, 1.0, 0.0), // Enter from bottom left
                 enter_end: identity,                                  // End in place
+                ..Default::default()
             },
             TransitionVariant::SlideDiagonalDownLeft => TransitionConfig {
                 exit_start: identity,                                 // Start in place
                 exit_end: Transform::new(-100.0, 100.0, 1.0, 0.0),    // Exit down and left
                 enter_start: Transform::new(100.0, -100.0, 1.0, 0.0), // Enter from top right
                 enter_end: identity,                                  // End in place
+                ..Default::default()
             },
             TransitionVariant::SlideDiagonalDownRight => TransitionConfig {
                 exit_start: identity,                                  // Start in place
                 exit_end: Transform::new(100.0, 100.0, 1.0, 0.0),      // Exit down and right
                 enter_start: Transform::new(-100.0, -100.0, 1.0, 0.0), // Enter from top left
                 enter_end: identity,                                   // End in place
+                ..Default::default()
             },
             TransitionVariant::SpiralIn => TransitionConfig {
                 exit_start: identity,                            // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 0.0),    // No change
                 enter_start: Transform::new(0.0, 0.0, 0.0, 0.0), // Start as nothing
                 enter_end: identity,                             // Grow to full size
+                ..Default::default()
             },
             TransitionVariant::SpiralOut => TransitionConfig {
                 exit_start: identity,                          // Start in place
                 exit_end: Transform::new(0.0, 0.0, 2.0, 0.0),  // Grow to twice size
                 enter_start: identity,                         // Start in place
                 enter_end: Transform::new(0.0, 0.0, 0.0, 0.0), // Shrink to full size
+                ..Default::default()
             },
             TransitionVariant::ElasticIn => TransitionConfig {
                 exit_start: identity,                              // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 0.0),      // No change
                 enter_start: Transform::new(0.0, 100.0, 1.0, 0.0), // Start from bottom
                 enter_end: identity,                               // End in place
+                ..Default::default()
             },
             TransitionVariant::ElasticOut => TransitionConfig {
                 exit_start: identity,                            // Start in place
                 exit_end: Transform::new(0.0, 100.0, 1.0, 0.0),  // Exit to bottom
                 enter_start: Transform::new(0.0, 0.0, 1.0, 0.0), // Start in place
                 enter_end: identity,                             // No change
+                ..Default::default()
             },
             TransitionVariant::SwingIn => TransitionConfig {
                 exit_start: identity,                              // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 0.0),      // No change
                 enter_start: Transform::new(0.0, 100.0, 1.0, 0.0), // Start from bottom
                 enter_end: identity,                               // End in place
+                ..Default::default()
             },
             TransitionVariant::SwingOut => TransitionConfig {
                 exit_start: identity,                            // Start in place
                 exit_end: Transform::new(0.0, 100.0, 1.0, 0.0),  // Exit to bottom
                 enter_start: Transform::new(0.0, 0.0, 1.0, 0.0), // Start in place
                 enter_end: identity,                             // No change
+                ..Default::default()
             },
             TransitionVariant::SlideLeftFade => TransitionConfig {
                 exit_start: identity,                              // Start in place
                 exit_end: Transform::new(-100.0, 0.0, 1.0, 0.0),   // Exit left
                 enter_start: Transform::new(100.0, 0.0, 1.0, 0.0), // Enter from right
                 enter_end: identity,                               // End in place
+                ..Default::default()
             },
             TransitionVariant::SlideRightFade => TransitionConfig {
                 exit_start: identity,                               // Start in place
                 exit_end: Transform::new(100.0, 0.0, 1.0, 0.0),     // Exit right
                 enter_start: Transform::new(-100.0, 0.0, 1.0, 0.0), // Enter from left
                 enter_end: identity,                                // End in place
+                ..Default::default()
             },
             TransitionVariant::ScaleRotateFade => TransitionConfig {
                 exit_start: identity,                            // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 0.0),    // No change
                 enter_start: Transform::new(0.0, 0.0, 0.0, 0.0), // Start as nothing
                 enter_end: identity,                             // Grow to full size
+                ..Default::default()
             },
             TransitionVariant::SlideFadeRotate => TransitionConfig {
                 exit_start: identity,                            // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 0.0),    // No change
                 enter_start: Transform::new(0.0, 0.0, 0.0, 0.0), // Start as nothing
                 enter_end: identity,                             // Grow to full size
+                ..Default::default()
             },
             TransitionVariant::ScaleFadeFlip => TransitionConfig {
                 exit_start: identity,                            // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 0.0),    // No change
                 enter_start: Transform::new(0.0, 0.0, 0.0, 0.0), // Start as nothing
                 enter_end: identity,                             // Grow to full size
+                ..Default::default()
             },
             TransitionVariant::RotateScaleSlide => TransitionConfig {
                 exit_start: identity,                            // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 0.0),    // No change
                 enter_start: Transform::new(0.0, 0.0, 0.0, 0.0), // Start as nothing
                 enter_end: identity,                             // Grow to full size
+                ..Default::default()
             },
         }
     }